@@ -0,0 +1,128 @@
+//! Compiles the awesome snapshot into a page per entry.
+//!
+//! Every entry in `awesome_snapshot.json` gets a route under `/awesome/`, named after the slug of its
+//! name. Prebuild also saves the README of every GitHub entry to `awesome_readmes/<slug>.md`. Those
+//! are rendered like the blog posts, after `readme.rs` has made them safe to put on the site.
+//!
+//! The output is an `AWESOME_READMES` table, an `AwesomeRoute` enum with one route per entry and a
+//! component for each of those routes. It is included by `src/components/awesome/page.rs`.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::posts::{prefixed_variant_name, render_markdown_with};
+use crate::readme::{sanitize_readme, Repo};
+
+const SNAPSHOT: &str = "awesome_snapshot.json";
+const README_DIR: &str = "awesome_readmes";
+
+struct Page {
+    slug: String,
+    html: Option<String>,
+}
+
+pub(crate) fn build(out_dir: &Path) {
+    println!("cargo:rerun-if-changed={SNAPSHOT}");
+    if Path::new(README_DIR).exists() {
+        println!("cargo:rerun-if-changed={README_DIR}");
+    }
+
+    let json = std::fs::read_to_string(SNAPSHOT).unwrap();
+    let snapshot: serde_json::Value =
+        serde_json::from_str(&json).unwrap_or_else(|err| panic!("{SNAPSHOT}: {err}"));
+    if snapshot["items"].as_array().map_or(true, |items| items.is_empty()) {
+        println!(
+            "cargo:warning={SNAPSHOT} has no entries, the awesome page is prerendered empty. \
+             Run `cargo run --features prebuild -- vendor-awesome` first."
+        );
+    }
+
+    let mut pages = Vec::new();
+    let mut variants = HashSet::new();
+    for item in snapshot["items"].as_array().into_iter().flatten() {
+        let Some(name) = item["name"].as_str() else {
+            continue;
+        };
+        let slug = slug(name);
+        if slug.is_empty() || !variants.insert(prefixed_variant_name("AwesomePage", &slug)) {
+            println!("cargo:warning=skipping the page of {name:?}, it has no unique slug");
+            continue;
+        }
+
+        let repo = item["github"].as_object().and_then(|github| {
+            Some(Repo {
+                username: github.get("username")?.as_str()?.to_string(),
+                repo: github.get("repo")?.as_str()?.to_string(),
+            })
+        });
+        let readme = Path::new(README_DIR).join(format!("{slug}.md"));
+        let html = match (repo, std::fs::read_to_string(readme)) {
+            (Some(repo), Ok(markdown)) => Some(render_readme(&markdown, repo)),
+            _ => None,
+        };
+        pages.push(Page { slug, html });
+    }
+
+    std::fs::write(out_dir.join("awesome.rs"), generate(&pages)).unwrap();
+}
+
+/// The slug of an entry, e.g. "Dioxus Free Icons" -> "dioxus-free-icons". `Item::slug` in
+/// `src/components/awesome/mod.rs` has to agree with this.
+fn slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+fn render_readme(markdown: &str, repo: Repo) -> String {
+    render_markdown_with(markdown, |events| sanitize_readme(events, repo))
+}
+
+fn generate(pages: &[Page]) -> String {
+    let mut out = String::new();
+
+    out.push_str("pub(crate) const AWESOME_READMES: &[(&str, &str)] = &[\n");
+    for page in pages {
+        if let Some(html) = &page.html {
+            _ = writeln!(out, "    ({:?}, {:?}),", page.slug, html);
+        }
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("#[derive(Clone, Routable, PartialEq, Eq, Serialize, Deserialize, Debug)]\n");
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) enum AwesomeRoute {\n");
+    for page in pages {
+        _ = writeln!(out, "    #[route(\"/{}\")]", page.slug);
+        _ = writeln!(out, "    {} {{}},", prefixed_variant_name("AwesomePage", &page.slug));
+    }
+    out.push_str("    // Entries added to the list after the site was built\n");
+    out.push_str("    #[route(\"/:slug\")]\n");
+    out.push_str("    AwesomeUnknownPage { slug: String },\n");
+    out.push_str("}\n\n");
+
+    for page in pages {
+        let variant = prefixed_variant_name("AwesomePage", &page.slug);
+        _ = writeln!(out, "#[component]");
+        _ = writeln!(out, "fn {variant}() -> Element {{");
+        _ = writeln!(
+            out,
+            "    rsx! {{ AwesomeItemPage {{ slug: {:?}.to_string() }} }}",
+            page.slug
+        );
+        _ = writeln!(out, "}}\n");
+    }
+    out.push_str("#[component]\n");
+    out.push_str("fn AwesomeUnknownPage(slug: String) -> Element {\n");
+    out.push_str("    rsx! { AwesomeItemPage { slug: slug } }\n");
+    out.push_str("}\n");
+
+    out
+}
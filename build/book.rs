@@ -0,0 +1,463 @@
+//! Collects the markdown source files of every version of the docs and renders them.
+//!
+//! A `BookRoute` only knows the url of a page, not which file it came from. A page at `/guide` can
+//! come from `guide.md` or `guide/index.md`, so we record every file that exists and let
+//! `BookRoute::source_path` pick the right one. The list for `docs-src/0.5` is written to
+//! `book_sources_0_5.rs` and included by the matching router module in `src/main.rs`.
+//!
+//! Next to that we write `languages_0_5.rs` with every language declared in the `book.toml` of the
+//! version and the pages that have been translated to it. A `SUMMARY.md` in the folder of a language
+//! translates the chapter titles of the sidebar.
+//!
+//! The `SUMMARY.md` of the version is turned into `summary_0_5.rs`: the chapters of the sidebar, and
+//! the `BookRoute` with a static route for every page they link to. We don't use the router of
+//! `use_mdbook` for this, because it also compiles every page into a component of its own, and the
+//! pages are already rendered here.
+//!
+//! Every page of every language is rendered to rsx by [`markdown`] into `pages_0_5.rs`, so the code
+//! blocks are components with their full info string instead of a bare `pre`. The docs layout
+//! renders the page from `RENDERED_PAGES` for the route, and the file also holds the
+//! `TranslatedRoute` with a static route for every translated page, which prerender and the search
+//! index pick up, and a catch-all route for the pages that fall back to English. Every language with
+//! translated pages gets its own chunk of the search index.
+
+use crate::markdown::{self, Highlighter, PageLocation, RenderedMarkdown};
+use crate::posts::prefixed_variant_name;
+use pulldown_cmark::{Event, Parser, Tag};
+use std::fmt::Write;
+use std::path::Path;
+
+pub(crate) const DOCS_DIR: &str = "docs-src";
+
+/// The language every page of the docs is written in
+pub(crate) const DEFAULT_LANGUAGE: &str = "en";
+
+pub(crate) fn build(out_dir: &Path) {
+    println!("cargo:rerun-if-changed={DOCS_DIR}");
+
+    for entry in std::fs::read_dir(DOCS_DIR).unwrap() {
+        let version_dir = entry.unwrap().path();
+        if !version_dir.is_dir() {
+            continue;
+        }
+        let version = version_dir.file_name().unwrap().to_string_lossy().into_owned();
+        let book_dir = version_dir.join(DEFAULT_LANGUAGE);
+
+        let mut sources = Vec::new();
+        collect_markdown(&book_dir, &book_dir, &mut sources);
+        sources.sort();
+
+        let mut out = String::from("pub(crate) const BOOK_SOURCES: &[&str] = &[\n");
+        for source in &sources {
+            _ = writeln!(out, "    {source:?},");
+        }
+        out.push_str("];\n");
+
+        let file_name = format!("book_sources_{}.rs", version.replace('.', "_"));
+        std::fs::write(out_dir.join(file_name), out).unwrap();
+
+        let file_name = format!("languages_{}.rs", version.replace('.', "_"));
+        std::fs::write(out_dir.join(file_name), languages(&version_dir)).unwrap();
+
+        let summary = std::fs::read_to_string(version_dir.join("SUMMARY.md")).unwrap();
+        let chapters = summary_chapters(&summary);
+        let mut routed = Vec::new();
+        linked_sources(&chapters, &sources, &mut routed);
+
+        let file_name = format!("summary_{}.rs", version.replace('.', "_"));
+        std::fs::write(out_dir.join(file_name), summary_routes(&chapters, &routed)).unwrap();
+
+        let file_name = format!("pages_{}.rs", version.replace('.', "_"));
+        std::fs::write(out_dir.join(file_name), pages(&version, &version_dir, &routed)).unwrap();
+    }
+}
+
+/// The markdown files translated to a language, relative to the folder of that language
+fn translated_sources(version_dir: &Path, code: &str) -> Vec<String> {
+    let book_dir = version_dir.join(code);
+    let mut sources = Vec::new();
+    if book_dir.is_dir() {
+        collect_markdown(&book_dir, &book_dir, &mut sources);
+    }
+    sources.retain(|source| source != "SUMMARY.md");
+    sources.sort();
+    sources
+}
+
+/// Generate the `LANGUAGES` table for a version of the docs
+fn languages(version_dir: &Path) -> String {
+    let book_toml = std::fs::read_to_string(version_dir.join("book.toml")).unwrap_or_default();
+
+    let mut out = String::from("pub(crate) static LANGUAGES: &[Language] = &[\n");
+    for (code, name) in declared_languages(&book_toml) {
+        let sources = translated_sources(version_dir, &code);
+        let summary = std::fs::read_to_string(version_dir.join(&code).join("SUMMARY.md"));
+        let titles = summary.map(|summary| summary_titles(&summary)).unwrap_or_default();
+
+        _ = writeln!(out, "    Language {{");
+        _ = writeln!(out, "        code: {code:?},");
+        _ = writeln!(out, "        name: {name:?},");
+        _ = writeln!(out, "        sources: &{sources:?},");
+        _ = writeln!(out, "        titles: &{titles:?},");
+        _ = writeln!(out, "    }},");
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// The title of every chapter linked from a `SUMMARY.md`, by markdown file
+fn summary_titles(summary: &str) -> Vec<(String, String)> {
+    summary
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once('[')?;
+            let (title, rest) = rest.split_once("](")?;
+            let (path, _) = rest.split_once(')')?;
+            let path = path.trim().trim_start_matches("./");
+            (!path.is_empty()).then(|| (path.to_string(), title.to_string()))
+        })
+        .collect()
+}
+
+/// A chapter of a `SUMMARY.md`
+struct Chapter {
+    name: String,
+    /// The markdown file of the chapter, relative to the folder of the language. Draft chapters
+    /// don't have one.
+    source: Option<String>,
+    nested: Vec<Chapter>,
+}
+
+/// Read the chapters of a `SUMMARY.md` like mdbook does: links outside of a list are prefix and suffix
+/// chapters, and the items of the lists are numbered chapters nested like the lists. Titles,
+/// separators and comments are left out.
+fn summary_chapters(summary: &str) -> Vec<Chapter> {
+    let mut chapters = Vec::new();
+    // The chapters we are in, innermost last
+    let mut open: Vec<Chapter> = Vec::new();
+    let mut list_depth = 0;
+    let mut in_link = false;
+    for event in Parser::new(summary) {
+        match event {
+            Event::Start(Tag::List(_)) => list_depth += 1,
+            Event::End(Tag::List(_)) => list_depth -= 1,
+            Event::Start(Tag::Item) => open.push(Chapter {
+                name: String::new(),
+                source: None,
+                nested: Vec::new(),
+            }),
+            Event::End(Tag::Item) => {
+                let chapter = open.pop().unwrap();
+                if chapter.name.is_empty() {
+                    continue;
+                }
+                match open.last_mut() {
+                    Some(parent) => parent.nested.push(chapter),
+                    None => chapters.push(chapter),
+                }
+            }
+            Event::Start(Tag::Link(_, destination, _)) => {
+                let source = destination.trim().trim_start_matches("./");
+                let source = (!source.is_empty()).then(|| source.to_string());
+                if list_depth == 0 {
+                    open.push(Chapter {
+                        name: String::new(),
+                        source,
+                        nested: Vec::new(),
+                    });
+                } else if let Some(item) = open.last_mut() {
+                    if item.name.is_empty() {
+                        item.source = source;
+                    }
+                }
+                in_link = true;
+            }
+            Event::End(Tag::Link(..)) => {
+                in_link = false;
+                // Prefix and suffix chapters are links on their own
+                if list_depth == 0 {
+                    chapters.extend(open.pop().filter(|chapter| !chapter.name.is_empty()));
+                }
+            }
+            Event::Text(text) | Event::Code(text) if in_link => {
+                if let Some(chapter) = open.last_mut() {
+                    chapter.name.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+    chapters
+}
+
+/// The sources of the chapters that link to a file of the book, in the order of the summary
+fn linked_sources(chapters: &[Chapter], sources: &[String], linked: &mut Vec<String>) {
+    for chapter in chapters {
+        if let Some(source) = &chapter.source {
+            if sources.contains(source) && !linked.contains(source) {
+                linked.push(source.clone());
+            }
+        }
+        linked_sources(&chapter.nested, sources, linked);
+    }
+}
+
+/// The name of the `BookRoute` variant of a page, e.g. `GettingStartedIndex` for
+/// `getting_started/index.md`
+fn route_variant_name(source: &str) -> String {
+    let slug = source.trim_end_matches(".md").replace(['/', '_'], "-");
+    prefixed_variant_name("", &slug)
+}
+
+/// Generate the `BookRoute` of every page in `routed`, and the `SUMMARY` the sidebar is rendered from
+fn summary_routes(chapters: &[Chapter], routed: &[String]) -> String {
+    let mut out = String::new();
+    out.push_str("#[derive(Clone, Copy, Routable, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, Debug)]\n");
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) enum BookRoute {\n");
+    for source in routed {
+        let url = page_url(source);
+        let url = if url.is_empty() { "/" } else { url.as_str() };
+        _ = writeln!(out, "    #[route(\"{url}\")]");
+        _ = writeln!(out, "    {} {{}},", route_variant_name(source));
+    }
+    out.push_str("}\n\n");
+
+    // The first page of the summary is the index of the book
+    let index = routed.first().expect("the SUMMARY.md doesn't link to any page");
+    out.push_str("impl Default for BookRoute {\n");
+    out.push_str("    fn default() -> Self {\n");
+    _ = writeln!(out, "        Self::{} {{}}", route_variant_name(index));
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+
+    // The docs layout renders the page of the route from `RENDERED_PAGES`, not the route itself
+    for source in routed {
+        _ = writeln!(out, "#[component]");
+        _ = writeln!(out, "fn {}() -> Element {{", route_variant_name(source));
+        _ = writeln!(out, "    None");
+        _ = writeln!(out, "}}\n");
+    }
+
+    out.push_str("pub(crate) static SUMMARY: &[Chapter] = &");
+    write_chapters(&mut out, chapters, routed, 0);
+    out.push_str(";\n");
+    out
+}
+
+fn write_chapters(out: &mut String, chapters: &[Chapter], routed: &[String], depth: usize) {
+    let indent = "    ".repeat(depth + 1);
+    out.push_str("[\n");
+    for chapter in chapters {
+        let location = match &chapter.source {
+            Some(source) if routed.contains(source) => {
+                format!("Some(BookRoute::{} {{}})", route_variant_name(source))
+            }
+            _ => "None".to_string(),
+        };
+        _ = write!(
+            out,
+            "{indent}Chapter {{ name: {:?}, location: {location}, nested_items: &",
+            chapter.name
+        );
+        write_chapters(out, &chapter.nested, routed, depth + 1);
+        out.push_str(" },\n");
+    }
+    out.push_str(&"    ".repeat(depth));
+    out.push(']');
+}
+
+/// A page of the book in one language, rendered to rsx
+struct Page {
+    language: String,
+    /// The markdown file, relative to the folder of the language
+    source: String,
+    /// The url of the page relative to the root of the book, the same in every language
+    url: String,
+    rendered: RenderedMarkdown,
+}
+
+impl Page {
+    fn variant_name(&self) -> String {
+        let slug = format!("{}{}", self.language, self.url).replace(['/', '_'], "-");
+        prefixed_variant_name("Translated", &slug)
+    }
+}
+
+/// The url of the page a markdown file is rendered to, relative to the root of the book
+fn page_url(source: &str) -> String {
+    let url = source.trim_end_matches(".md");
+    let url = match url.strip_suffix("index") {
+        Some(dir) if dir.is_empty() || dir.ends_with('/') => dir.trim_end_matches('/'),
+        _ => url,
+    };
+    if url.is_empty() {
+        String::new()
+    } else {
+        format!("/{url}")
+    }
+}
+
+/// Generate `RENDERED_PAGES` with every page of a version of the docs that has a route, in every
+/// language, and the `TranslatedRoute` of the translated pages
+fn pages(version: &str, version_dir: &Path, routed: &[String]) -> String {
+    let book_toml = std::fs::read_to_string(version_dir.join("book.toml")).unwrap_or_default();
+    let highlighter = Highlighter::new();
+
+    let mut pages = Vec::new();
+    for (code, _) in declared_languages(&book_toml) {
+        let (mut sources, book_url) = if code == DEFAULT_LANGUAGE {
+            (routed.to_vec(), format!("/learn/{version}"))
+        } else {
+            (translated_sources(version_dir, &code), format!("/learn/{version}/{code}"))
+        };
+        sources.retain(|source| routed.contains(source));
+        for source in sources {
+            let markdown = std::fs::read_to_string(version_dir.join(&code).join(&source)).unwrap();
+            let location = PageLocation {
+                source: &source,
+                book_url: &book_url,
+            };
+            let rendered = markdown::render(&crate::includes::expand(&markdown), &location, &highlighter);
+            pages.push(Page {
+                url: page_url(&source),
+                language: code.clone(),
+                source,
+                rendered,
+            });
+        }
+    }
+    let translations: Vec<&Page> = pages
+        .iter()
+        .filter(|page| page.language != DEFAULT_LANGUAGE)
+        .collect();
+
+    let mut out = String::new();
+    out.push_str("pub(crate) static RENDERED_PAGES: &[RenderedPage] = &[\n");
+    for (index, page) in pages.iter().enumerate() {
+        _ = writeln!(out, "    RenderedPage {{");
+        _ = writeln!(out, "        language: {:?},", page.language);
+        _ = writeln!(out, "        source: {:?},", page.source);
+        _ = writeln!(out, "        headings: &[");
+        for (level, title, id) in &page.rendered.headings {
+            _ = writeln!(out, "            Heading {{ level: {level}, title: {title:?}, id: {id:?} }},");
+        }
+        _ = writeln!(out, "        ],");
+        _ = writeln!(out, "        render: page_{index},");
+        _ = writeln!(out, "    }},");
+    }
+    out.push_str("];\n\n");
+
+    for (index, page) in pages.iter().enumerate() {
+        _ = writeln!(out, "fn page_{index}() -> Element {{");
+        _ = writeln!(out, "    rsx! {{");
+        out.push_str(&page.rendered.rsx);
+        _ = writeln!(out, "    }}");
+        _ = writeln!(out, "}}\n");
+    }
+
+    out.push_str("#[derive(Clone, Routable, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]\n");
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) enum TranslatedRoute {\n");
+    for translation in &translations {
+        _ = writeln!(out, "    #[route(\"/{}{}\")]", translation.language, translation.url);
+        _ = writeln!(out, "    {} {{}},", translation.variant_name());
+    }
+    out.push_str("    // Pages that haven't been translated, which fall back to English\n");
+    out.push_str("    #[route(\"/:lang/:..segments\")]\n");
+    out.push_str("    Untranslated { lang: String, segments: Vec<String> },\n");
+    out.push_str("}\n\n");
+
+    out.push_str("impl TranslatedRoute {\n");
+    out.push_str("    /// The code of the language of the page\n");
+    out.push_str("    pub(crate) fn language(&self) -> &str {\n");
+    out.push_str("        match self {\n");
+    for translation in &translations {
+        _ = writeln!(out, "            Self::{} {{}} => {:?},", translation.variant_name(), translation.language);
+    }
+    out.push_str("            Self::Untranslated { lang, .. } => lang,\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n\n");
+    out.push_str("    /// The url of the page relative to the root of the book\n");
+    out.push_str("    pub(crate) fn page_url(&self) -> String {\n");
+    out.push_str("        match self {\n");
+    for translation in &translations {
+        _ = writeln!(out, "            Self::{} {{}} => {:?}.to_string(),", translation.variant_name(), translation.url);
+    }
+    out.push_str("            Self::Untranslated { segments, .. } => format!(\"/{}\", segments.join(\"/\")),\n");
+    out.push_str("        }\n");
+    out.push_str("    }\n");
+    out.push_str("}\n\n");
+
+    // The docs layout renders the page of the route from `RENDERED_PAGES`, not the route itself
+    for translation in &translations {
+        _ = writeln!(out, "#[component]");
+        _ = writeln!(out, "fn {}() -> Element {{", translation.variant_name());
+        _ = writeln!(out, "    None");
+        _ = writeln!(out, "}}\n");
+    }
+    out.push_str("#[component]\n");
+    out.push_str("fn Untranslated(lang: String, segments: Vec<String>) -> Element {\n");
+    out.push_str("    None\n");
+    out.push_str("}\n\n");
+
+    // Every language with translated pages gets its own chunk of the search index
+    let mut languages: Vec<&str> = translations.iter().map(|t| t.language.as_str()).collect();
+    languages.dedup();
+    out.push_str("pub(crate) static TRANSLATION_SEARCH_CHUNKS: &[crate::search::SearchChunk] = &[\n");
+    for language in &languages {
+        _ = writeln!(
+            out,
+            "    crate::search::SearchChunk {{ section: {language:?}, name: \"search-{language}\" }},"
+        );
+    }
+    out.push_str("];\n");
+
+    out
+}
+
+/// Find the `[language.<code>]` tables of a `book.toml` and their `name`
+fn declared_languages(book_toml: &str) -> Vec<(String, String)> {
+    let mut languages: Vec<(String, String)> = Vec::new();
+    let mut in_language = false;
+
+    for line in book_toml.lines().map(str::trim) {
+        if line.starts_with('[') {
+            let table = line.trim_matches(|c| c == '[' || c == ']');
+            in_language = match table.strip_prefix("language.") {
+                Some(code) => {
+                    languages.push((code.to_string(), code.to_string()));
+                    true
+                }
+                None => false,
+            };
+        } else if in_language {
+            if let Some((key, value)) = line.split_once('=') {
+                if key.trim() == "name" {
+                    languages.last_mut().unwrap().1 = value.trim().trim_matches('"').to_string();
+                }
+            }
+        }
+    }
+
+    // English is the language every page falls back to, so it always comes first
+    languages.sort_by_key(|(code, _)| code != DEFAULT_LANGUAGE);
+    languages
+}
+
+/// Find every markdown file in the directory, relative to the root of the book and with `/` separators
+fn collect_markdown(root: &Path, dir: &Path, sources: &mut Vec<String>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_markdown(root, &path, sources);
+        } else if path.extension().map_or(false, |ext| ext == "md") {
+            let relative = path.strip_prefix(root).unwrap();
+            let components: Vec<_> = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            sources.push(components.join("/"));
+        }
+    }
+}
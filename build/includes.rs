@@ -0,0 +1,346 @@
+//! Checks the `{{#include path:anchor}}` directives and `inject-dioxus` blocks of the docs.
+//!
+//! mdbook renders an include of a missing file or anchor as an empty code block, so a typo only shows
+//! up when someone reads the page. We fail the build on those instead. Anchors in
+//! `src/doc_examples` that no page includes and examples no page references are only warned about.
+//!
+//! Every example that is injected into a page is written to `injected_examples.rs`, so the example
+//! tests render exactly the examples the docs show.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+const EXAMPLES_DIR: &str = "src/doc_examples";
+
+/// Check the includes of every page in `docs_dir` and write `injected_examples.rs` to `out_dir`
+pub(crate) fn check(docs_dir: &Path, out_dir: &Path) {
+    println!("cargo:rerun-if-changed={EXAMPLES_DIR}");
+
+    let mut pages = Vec::new();
+    collect_files(docs_dir, "md", &mut pages);
+    pages.sort();
+
+    let mut errors = Vec::new();
+    // Every anchor that is included somewhere, by file
+    let mut used_anchors: BTreeMap<PathBuf, BTreeSet<String>> = BTreeMap::new();
+    // Every doc example module that is included or injected somewhere
+    let mut referenced: BTreeSet<String> = BTreeSet::new();
+    // The path of every doc example component that is injected somewhere
+    let mut injected: BTreeSet<String> = BTreeSet::new();
+
+    for page in &pages {
+        let markdown = std::fs::read_to_string(page).unwrap();
+
+        for include in includes(&markdown) {
+            let (path, anchor) = match include.split_once(':') {
+                Some((path, anchor)) => (path, Some(anchor)),
+                None => (include, None),
+            };
+            let path = PathBuf::from(path.trim());
+            if let Some(module) = example_module(&path) {
+                referenced.insert(module);
+            }
+
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                errors.push(format!(
+                    "{}: included file {} does not exist",
+                    page.display(),
+                    path.display()
+                ));
+                continue;
+            };
+
+            // `file:10`, `file:10:20` and `file::20` include lines instead of an anchor
+            let Some(anchor) = anchor.filter(|anchor| !is_line_range(anchor)) else {
+                continue;
+            };
+            if !anchors(&source).contains(anchor) {
+                errors.push(format!(
+                    "{}: {} has no anchor `{anchor}`",
+                    page.display(),
+                    path.display()
+                ));
+            }
+            used_anchors
+                .entry(path)
+                .or_default()
+                .insert(anchor.to_string());
+        }
+
+        for (module, item, item_path) in injected_items(&markdown) {
+            let path = Path::new(EXAMPLES_DIR).join(format!("{module}.rs"));
+            let Ok(source) = std::fs::read_to_string(&path) else {
+                // Paths into other crates, like `manganis::mg`
+                continue;
+            };
+            referenced.insert(module.clone());
+            if !defines(&source, &item) {
+                errors.push(format!(
+                    "{}: `{module}::{item}` is injected, but {} doesn't define `{item}`",
+                    page.display(),
+                    path.display()
+                ));
+                continue;
+            }
+            injected.insert(item_path);
+        }
+    }
+
+    let mut examples = Vec::new();
+    collect_files(Path::new(EXAMPLES_DIR), "rs", &mut examples);
+    examples.sort();
+
+    let mut unreferenced = Vec::new();
+    for example in &examples {
+        let Some(module) = example_module(example) else {
+            continue;
+        };
+        if module == "mod" {
+            continue;
+        }
+        if !referenced.contains(&module) {
+            unreferenced.push(module);
+        }
+
+        let source = std::fs::read_to_string(example).unwrap();
+        let used = used_anchors.get(example);
+        for anchor in anchors(&source) {
+            if !used.map_or(false, |used| used.contains(&anchor)) {
+                println!(
+                    "cargo:warning={}: anchor `{anchor}` is not included by any page",
+                    example.display()
+                );
+            }
+        }
+    }
+
+    if !unreferenced.is_empty() {
+        println!(
+            "cargo:warning=doc examples not referenced from any page: {}",
+            unreferenced.join(", ")
+        );
+    }
+
+    if !errors.is_empty() {
+        panic!("broken includes in the docs:\n{}", errors.join("\n"));
+    }
+
+    let mut generated = String::from(
+        "/// Every doc example that is injected into a page, by its path in `doc_examples`\n\
+         const INJECTED_EXAMPLES: &[(&str, fn() -> Element)] = &[\n",
+    );
+    for path in &injected {
+        generated.push_str(&format!("    ({path:?}, {path}),\n"));
+    }
+    generated.push_str("];\n");
+    std::fs::write(out_dir.join("injected_examples.rs"), generated).unwrap();
+}
+
+/// The arguments of every `{{#include ...}}` in a page
+fn includes(markdown: &str) -> Vec<&str> {
+    let mut includes = Vec::new();
+    let mut rest = markdown;
+    while let Some(start) = rest.find("{{#include ") {
+        rest = &rest[start + "{{#include ".len()..];
+        let Some(end) = rest.find("}}") else {
+            break;
+        };
+        includes.push(rest[..end].trim());
+        rest = &rest[end..];
+    }
+    includes
+}
+
+/// Replace every `{{#include path}}` of a page with the file, the lines between the `ANCHOR: name`
+/// markers of `path:name`, or the lines of `path:start`, `path:start:end` and `path::end`. Files are
+/// read relative to the root of the repo, and `ANCHOR` markers are left out like mdbook does.
+///
+/// Panics if a file doesn't exist, [`check`] reports those with the page they are on first.
+pub(crate) fn expand(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut rest = markdown;
+    while let Some(start) = rest.find("{{#include ") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + "{{#include ".len()..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let include = after[..end].trim();
+        rest = &after[end + "}}".len()..];
+
+        let (path, selector) = match include.split_once(':') {
+            Some((path, selector)) => (path, Some(selector)),
+            None => (include, None),
+        };
+        let source = std::fs::read_to_string(path.trim())
+            .unwrap_or_else(|err| panic!("can't include {}: {err}", path.trim()));
+        let lines: Vec<&str> = source.lines().collect();
+        let selected: Vec<&str> = match selector {
+            None => lines,
+            Some(lines_selector) if is_line_range(lines_selector) => {
+                let mut bounds = lines_selector
+                    .split(':')
+                    .map(|bound| bound.parse::<usize>().ok());
+                let start = bounds.next().flatten().unwrap_or(1).max(1) - 1;
+                let end = bounds
+                    .next()
+                    .flatten()
+                    .unwrap_or(lines.len())
+                    .min(lines.len());
+                lines.get(start..end).unwrap_or_default().to_vec()
+            }
+            Some(anchor) => lines
+                .iter()
+                .skip_while(|line| !is_marker(line, "ANCHOR:", anchor))
+                .skip(1)
+                .take_while(|line| !is_marker(line, "ANCHOR_END:", anchor))
+                .copied()
+                .collect(),
+        };
+        for line in selected.iter().filter(|line| !line.contains("ANCHOR")) {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Whether the part after the path of an include is a range of lines, the 1-based and inclusive
+/// `start`, `start:end` or `:end`, instead of an anchor
+fn is_line_range(selector: &str) -> bool {
+    selector.starts_with(|c: char| c.is_ascii_digit() || c == ':')
+}
+
+fn is_marker(line: &str, marker: &str, anchor: &str) -> bool {
+    line.split_once(marker)
+        .map_or(false, |(_, name)| name.trim() == anchor)
+}
+
+/// The module, item and whole path of every `module::Item` in the `inject-dioxus` blocks of a page
+fn injected_items(markdown: &str) -> Vec<(String, String, String)> {
+    let mut items = Vec::new();
+    let mut in_block = false;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_block = trimmed == "```inject-dioxus";
+            continue;
+        }
+        if !in_block {
+            continue;
+        }
+
+        let words = line.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'));
+        for path in words.filter(|word| word.contains("::")) {
+            let segments: Vec<_> = path
+                .split("::")
+                .filter(|segment| !segment.is_empty())
+                .collect();
+            if let (Some(module), Some(item)) = (segments.first(), segments.last()) {
+                if segments.len() > 1 {
+                    items.push((module.to_string(), item.to_string(), segments.join("::")));
+                }
+            }
+        }
+    }
+    items
+}
+
+/// The names of the `ANCHOR: name` markers in a file
+fn anchors(source: &str) -> BTreeSet<String> {
+    source
+        .lines()
+        .filter_map(|line| line.split_once("ANCHOR:"))
+        .map(|(_, name)| name.trim().to_string())
+        .collect()
+}
+
+/// Whether the source has an item with this name. Good enough to catch typos without parsing rust.
+fn defines(source: &str, item: &str) -> bool {
+    let keywords = ["fn", "struct", "enum", "mod", "const", "static", "type"];
+    let endings = [' ', '(', '<', ':', ';', '{'];
+    keywords.iter().any(|keyword| {
+        let declaration = format!("{keyword} {item}");
+        source
+            .match_indices(&declaration)
+            .any(|(index, _)| source[index + declaration.len()..].starts_with(&endings[..]))
+    })
+}
+
+/// The name of the module a file in `src/doc_examples` defines
+fn example_module(path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(EXAMPLES_DIR).ok()?;
+    Some(relative.file_stem()?.to_string_lossy().into_owned())
+}
+
+fn collect_files(dir: &Path, extension: &str, files: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_files(&path, extension, files);
+        } else if path.extension().map_or(false, |ext| ext == extension) {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn finds_includes() {
+    let markdown = "{{#include src/doc_examples/readme.rs}}\n```rust\n{{#include src/a.rs:app }}\n```\n{{#include broken";
+    assert_eq!(
+        includes(markdown),
+        ["src/doc_examples/readme.rs", "src/a.rs:app"]
+    );
+}
+
+#[test]
+fn expands_anchors_and_line_ranges() {
+    let dir = std::env::temp_dir().join(format!("includes-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("example.rs");
+    std::fs::write(
+        &file,
+        "use a;\n// ANCHOR: app\nfn app() {}\n// ANCHOR_END: app\nfn other() {}\n",
+    )
+    .unwrap();
+    let expand =
+        |selector: &str| expand(&format!("[{{{{#include {}{selector}}}}}]", file.display()));
+
+    assert_eq!(expand(""), "[use a;\nfn app() {}\nfn other() {}\n]");
+    assert_eq!(expand(":app"), "[fn app() {}\n]");
+    assert_eq!(expand(":3:3"), "[fn app() {}\n]");
+    assert_eq!(expand(":5"), "[fn other() {}\n]");
+    assert_eq!(expand("::1"), "[use a;\n]");
+    assert_eq!(expand(":4:100"), "[fn other() {}\n]");
+    assert_eq!(expand(":missing"), "[]");
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "can't include")]
+fn expanding_a_missing_file_panics() {
+    expand("{{#include src/doc_examples/does_not_exist.rs}}");
+}
+
+#[test]
+fn finds_injected_items() {
+    let markdown = "```rust\nreadme::App {}\n```\n```inject-dioxus\nDemoFrame {\n    hackernews_async::fetch::App {}\n}\n```\n";
+    assert_eq!(
+        injected_items(markdown),
+        [(
+            "hackernews_async".to_string(),
+            "App".to_string(),
+            "hackernews_async::fetch::App".to_string()
+        )]
+    );
+}
+
+#[test]
+fn finds_definitions() {
+    let source = "pub fn App() -> Element {}\nstruct Props<T>;\nfn Application() {}";
+    assert!(defines(source, "App"));
+    assert!(defines(source, "Props"));
+    assert!(!defines(source, "Applic"));
+    assert!(!defines(source, "Missing"));
+}
@@ -0,0 +1,22 @@
+//! Generates the parts of the site that are derived from files in the repo at build time.
+//!
+//! Everything is written to `OUT_DIR` and pulled in with `include!`. Before that, the includes of the
+//! docs are checked so a broken one fails the build instead of rendering an empty code block.
+
+use std::path::{Path, PathBuf};
+
+mod awesome;
+mod book;
+mod includes;
+mod markdown;
+mod posts;
+mod readme;
+
+fn main() {
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    includes::check(Path::new(book::DOCS_DIR), &out_dir);
+
+    posts::build(&out_dir);
+    book::build(&out_dir);
+    awesome::build(&out_dir);
+}
@@ -0,0 +1,682 @@
+//! Turns a page of the docs into rsx.
+//!
+//! Every code block becomes a `CodeBlock` component with its lines highlighted by syntect, so the
+//! copy button, filename, highlighted lines and run button are part of the page instead of being
+//! added to the DOM after it rendered. Consecutive blocks of the same `group` become the tabs of one
+//! `CodeGroup`, `inject-dioxus` blocks are pasted in as they are, and links to other markdown files
+//! of the book become `Link`s to the page in the same language.
+
+use crate::posts::{slugify, SYNTECT_THEME};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::html::{styled_line_to_highlighted_html, IncludeBackground};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+/// The syntaxes and theme every code block is highlighted with, loaded once per build
+pub(crate) struct Highlighter {
+    syntaxes: SyntaxSet,
+    theme: Theme,
+}
+
+impl Highlighter {
+    pub(crate) fn new() -> Self {
+        let mut themes = ThemeSet::load_defaults();
+        Self {
+            syntaxes: SyntaxSet::load_defaults_newlines(),
+            theme: themes.themes.remove(SYNTECT_THEME).unwrap(),
+        }
+    }
+
+    /// The highlighted html of every line of the code, without the line endings
+    fn lines(&self, language: &str, code: &str) -> Vec<String> {
+        let syntax = self
+            .syntaxes
+            .find_syntax_by_token(language)
+            .unwrap_or_else(|| self.syntaxes.find_syntax_plain_text());
+        let mut highlighter = HighlightLines::new(syntax, &self.theme);
+        LinesWithEndings::from(code)
+            .map(|line| {
+                let regions = highlighter.highlight_line(line, &self.syntaxes).unwrap();
+                let regions: Vec<_> = regions
+                    .into_iter()
+                    .map(|(style, text)| (style, text.trim_end_matches(['\n', '\r'])))
+                    .collect();
+                styled_line_to_highlighted_html(&regions, IncludeBackground::No).unwrap()
+            })
+            .collect()
+    }
+}
+
+/// A page of the docs turned into rsx
+pub(crate) struct RenderedMarkdown {
+    /// The body of an `rsx!` call
+    pub(crate) rsx: String,
+    /// The level, title and id of every heading
+    pub(crate) headings: Vec<(usize, String, String)>,
+}
+
+/// Where a page lives, to resolve its relative links
+pub(crate) struct PageLocation<'a> {
+    /// The markdown file, relative to the root of the book
+    pub(crate) source: &'a str,
+    /// The url of the root of the book in the language of the page, e.g. `/learn/0.5/pt-br`
+    pub(crate) book_url: &'a str,
+}
+
+enum Node {
+    /// An element or component with its attributes as rust expressions
+    Element {
+        name: &'static str,
+        attributes: Vec<(&'static str, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+    /// The contents of an `inject-dioxus` block
+    Rsx(String),
+    Code(Code),
+    /// Consecutive code blocks of the same group
+    Group(Vec<Code>),
+}
+
+struct Code {
+    info: String,
+    code: String,
+    lines: Vec<String>,
+}
+
+impl Code {
+    fn group(&self) -> Option<&str> {
+        self.info.split(',').find_map(|part| {
+            let (key, value) = part.split_once('=')?;
+            (key.trim() == "group").then(|| value.trim().trim_matches('"'))
+        })
+    }
+}
+
+fn element(name: &'static str, attributes: Vec<(&'static str, String)>) -> Node {
+    Node::Element {
+        name,
+        attributes,
+        children: Vec::new(),
+    }
+}
+
+pub(crate) fn render(
+    markdown: &str,
+    location: &PageLocation,
+    highlighter: &Highlighter,
+) -> RenderedMarkdown {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+
+    // The root is never written out, only its children
+    let mut stack = vec![element("div", Vec::new())];
+    let mut headings = Vec::new();
+    let mut code: Option<(String, String)> = None;
+    let mut table_alignments: Vec<Alignment> = Vec::new();
+    let mut table_cell = 0;
+    let mut in_table_head = false;
+    // Raw html comes a line or a tag at a time
+    let mut html = String::new();
+    let mut footnotes: Vec<String> = Vec::new();
+    let mut footnote_number = |label: &str| match footnotes.iter().position(|known| known == label) {
+        Some(index) => index + 1,
+        None => {
+            footnotes.push(label.to_string());
+            footnotes.len()
+        }
+    };
+
+    for event in Parser::new_ext(markdown, options) {
+        // The text inside of inline html like `<kbd>a</kbd>` goes in the same element as its tags
+        if unclosed_tags(&html) > 0 {
+            let inner = match &event {
+                Event::Text(text) if code.is_none() => Some(escape_html(text)),
+                Event::Code(text) => Some(format!("<code>{}</code>", escape_html(text))),
+                Event::SoftBreak => Some("\n".to_string()),
+                _ => None,
+            };
+            if let Some(inner) = inner {
+                html.push_str(&inner);
+                continue;
+            }
+        }
+        if !matches!(event, Event::Html(_)) {
+            flush_html(&mut stack, &mut html);
+        }
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let info = match kind {
+                    CodeBlockKind::Fenced(info) => info.trim().to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code = Some((info, String::new()));
+            }
+            Event::Text(text) if code.is_some() => code.as_mut().unwrap().1.push_str(&text),
+            Event::End(Tag::CodeBlock(_)) => {
+                let (info, source) = code.take().unwrap();
+                let node = if info == "inject-dioxus" {
+                    Node::Rsx(source)
+                } else {
+                    let language = info.split(',').next().unwrap_or_default().trim();
+                    Node::Code(Code {
+                        lines: highlighter.lines(language, &source),
+                        info,
+                        code: source,
+                    })
+                };
+                push(&mut stack, node);
+            }
+
+            Event::Start(tag) => {
+                let node = match tag {
+                    Tag::Paragraph => element("p", Vec::new()),
+                    Tag::Heading(level, ..) => element(heading_tag(level as usize), Vec::new()),
+                    Tag::BlockQuote => element("blockquote", Vec::new()),
+                    Tag::List(Some(1)) => element("ol", Vec::new()),
+                    Tag::List(Some(start)) => element("ol", vec![("start", literal(&start.to_string()))]),
+                    Tag::List(None) => element("ul", Vec::new()),
+                    Tag::Item => element("li", Vec::new()),
+                    Tag::FootnoteDefinition(label) => {
+                        let number = footnote_number(&label);
+                        let mut definition = element(
+                            "div",
+                            vec![
+                                ("class", literal("footnote-definition")),
+                                ("id", literal(&label)),
+                            ],
+                        );
+                        if let Node::Element { children, .. } = &mut definition {
+                            let mut sup = element("sup", vec![("class", literal("footnote-definition-label"))]);
+                            push_child(&mut sup, Node::Text(number.to_string()));
+                            children.push(sup);
+                        }
+                        definition
+                    }
+                    Tag::Table(alignments) => {
+                        table_alignments = alignments;
+                        element("table", Vec::new())
+                    }
+                    Tag::TableHead => {
+                        in_table_head = true;
+                        table_cell = 0;
+                        stack.push(element("thead", Vec::new()));
+                        element("tr", Vec::new())
+                    }
+                    Tag::TableRow => {
+                        table_cell = 0;
+                        element("tr", Vec::new())
+                    }
+                    Tag::TableCell => {
+                        let alignment = match table_alignments.get(table_cell) {
+                            Some(Alignment::Left) => Some("left"),
+                            Some(Alignment::Center) => Some("center"),
+                            Some(Alignment::Right) => Some("right"),
+                            _ => None,
+                        };
+                        table_cell += 1;
+                        let attributes = alignment
+                            .map(|alignment| vec![("text_align", literal(alignment))])
+                            .unwrap_or_default();
+                        element(if in_table_head { "th" } else { "td" }, attributes)
+                    }
+                    Tag::Emphasis => element("em", Vec::new()),
+                    Tag::Strong => element("strong", Vec::new()),
+                    Tag::Strikethrough => element("del", Vec::new()),
+                    Tag::Link(_, destination, title) => match resolve_link(&destination, location) {
+                        Some(url) => element("Link", vec![("to", literal(&url))]),
+                        None => {
+                            let mut attributes = vec![("href", literal(&destination))];
+                            if !title.is_empty() {
+                                attributes.push(("title", literal(&title)));
+                            }
+                            element("a", attributes)
+                        }
+                    },
+                    Tag::Image(_, destination, title) => {
+                        let mut attributes = vec![("src", literal(&resolve_image(&destination)))];
+                        if !title.is_empty() {
+                            attributes.push(("title", literal(&title)));
+                        }
+                        element("img", attributes)
+                    }
+                    Tag::CodeBlock(_) => unreachable!(),
+                };
+                stack.push(node);
+            }
+            Event::End(tag) => {
+                let mut node = stack.pop().unwrap();
+                match tag {
+                    Tag::Heading(level, ..) => {
+                        let Node::Element {
+                            attributes,
+                            children,
+                            ..
+                        } = &mut node
+                        else {
+                            unreachable!()
+                        };
+                        let title = text_of(children);
+                        let id = slugify(&title);
+                        attributes.push(("id", literal(&id)));
+                        let anchor = Node::Element {
+                            name: "a",
+                            attributes: vec![
+                                ("class", literal("header")),
+                                ("href", literal(&format!("#{id}"))),
+                            ],
+                            children: std::mem::take(children),
+                        };
+                        children.push(anchor);
+                        headings.push((level as usize, title, id));
+                    }
+                    Tag::Image(..) => {
+                        // The text of an image is its alt text
+                        let Node::Element {
+                            attributes,
+                            children,
+                            ..
+                        } = &mut node
+                        else {
+                            unreachable!()
+                        };
+                        let alt = text_of(children);
+                        children.clear();
+                        attributes.push(("alt", literal(&alt)));
+                    }
+                    Tag::TableHead => {
+                        in_table_head = false;
+                        push(&mut stack, node);
+                        node = stack.pop().unwrap();
+                    }
+                    Tag::TableRow => {
+                        // The body rows go in a tbody after the thead
+                        let Some(Node::Element { children, .. }) = stack.last_mut() else {
+                            unreachable!()
+                        };
+                        match children.last_mut() {
+                            Some(tbody @ Node::Element { name: "tbody", .. }) => {
+                                push_child(tbody, node);
+                            }
+                            _ => {
+                                let mut tbody = element("tbody", Vec::new());
+                                push_child(&mut tbody, node);
+                                children.push(tbody);
+                            }
+                        }
+                        continue;
+                    }
+                    _ => {}
+                }
+                push(&mut stack, node);
+            }
+
+            Event::Text(text) => push(&mut stack, Node::Text(text.to_string())),
+            Event::Code(text) => {
+                let mut code = element("code", Vec::new());
+                push_child(&mut code, Node::Text(text.to_string()));
+                push(&mut stack, code);
+            }
+            Event::Html(text) => html.push_str(&text),
+            Event::FootnoteReference(label) => {
+                let number = footnote_number(&label);
+                let mut link = element("a", vec![("href", literal(&format!("#{label}")))]);
+                push_child(&mut link, Node::Text(number.to_string()));
+                let mut sup = element("sup", vec![("class", literal("footnote-reference"))]);
+                push_child(&mut sup, link);
+                push(&mut stack, sup);
+            }
+            Event::SoftBreak => push(&mut stack, Node::Text("\n".to_string())),
+            Event::HardBreak => push(&mut stack, element("br", Vec::new())),
+            Event::Rule => push(&mut stack, element("hr", Vec::new())),
+            Event::TaskListMarker(checked) => push(
+                &mut stack,
+                element(
+                    "input",
+                    vec![
+                        ("r#type", literal("checkbox")),
+                        ("disabled", "true".to_string()),
+                        ("checked", checked.to_string()),
+                    ],
+                ),
+            ),
+        }
+    }
+
+    flush_html(&mut stack, &mut html);
+
+    let Some(Node::Element { children, .. }) = stack.pop() else {
+        unreachable!()
+    };
+    let mut rsx = String::new();
+    for node in &group_code_blocks(children) {
+        write_node(&mut rsx, node, 2);
+    }
+    RenderedMarkdown { rsx, headings }
+}
+
+/// Raw html is rare in the docs and can't be checked like rsx, so it keeps its own element
+fn flush_html(stack: &mut Vec<Node>, html: &mut String) {
+    if html.trim().is_empty() {
+        html.clear();
+        return;
+    }
+    let name = if stack.len() == 1 { "div" } else { "span" };
+    let inner = literal(html.trim());
+    html.clear();
+    push(stack, element(name, vec![("dangerous_inner_html", inner)]));
+}
+
+/// How many of the tags opened in some html are still open at its end
+fn unclosed_tags(html: &str) -> usize {
+    const VOID_ELEMENTS: &[&str] = &[
+        "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source",
+        "track", "wbr",
+    ];
+    let mut open = 0_usize;
+    for tag in html.split('<').skip(1) {
+        let tag = tag.split('>').next().unwrap_or_default();
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+        if tag.starts_with('/') {
+            open = open.saturating_sub(1);
+        } else if !(tag.starts_with('!') || tag.ends_with('/') || name.is_empty())
+            && !VOID_ELEMENTS.contains(&name.to_ascii_lowercase().as_str())
+        {
+            open += 1;
+        }
+    }
+    open
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn heading_tag(level: usize) -> &'static str {
+    ["h1", "h2", "h3", "h4", "h5", "h6"][level.clamp(1, 6) - 1]
+}
+
+/// Add a node to the element on top of the stack
+fn push(stack: &mut [Node], node: Node) {
+    push_child(stack.last_mut().unwrap(), node);
+}
+
+fn push_child(parent: &mut Node, node: Node) {
+    let Node::Element { children, .. } = parent else {
+        unreachable!()
+    };
+    match (children.last_mut(), node) {
+        (Some(Node::Text(last)), Node::Text(text)) => last.push_str(&text),
+        (_, node) => children.push(node),
+    }
+}
+
+/// The text inside of some nodes, like the title of a heading
+fn text_of(nodes: &[Node]) -> String {
+    let mut text = String::new();
+    for node in nodes {
+        match node {
+            Node::Text(t) => text.push_str(t),
+            Node::Element { children, .. } => text.push_str(&text_of(children)),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Merge consecutive code blocks of the same group into one node, everywhere in the tree
+fn group_code_blocks(nodes: Vec<Node>) -> Vec<Node> {
+    let mut grouped: Vec<Node> = Vec::new();
+    for node in nodes {
+        let node = match node {
+            Node::Element {
+                name,
+                attributes,
+                children,
+            } => Node::Element {
+                name,
+                attributes,
+                children: group_code_blocks(children),
+            },
+            node => node,
+        };
+        let Node::Code(code) = node else {
+            grouped.push(node);
+            continue;
+        };
+        let Some(group) = code.group().map(str::to_string) else {
+            grouped.push(Node::Code(code));
+            continue;
+        };
+        match grouped.pop() {
+            Some(Node::Group(mut blocks)) if blocks[0].group() == Some(group.as_str()) => {
+                blocks.push(code);
+                grouped.push(Node::Group(blocks));
+            }
+            Some(Node::Code(previous)) if previous.group() == Some(group.as_str()) => {
+                grouped.push(Node::Group(vec![previous, code]));
+            }
+            last => {
+                grouped.extend(last);
+                grouped.push(Node::Code(code));
+            }
+        }
+    }
+    grouped
+}
+
+fn write_node(out: &mut String, node: &Node, depth: usize) {
+    let indent = "    ".repeat(depth);
+    match node {
+        Node::Element {
+            name,
+            attributes,
+            children,
+        } => {
+            out.push_str(&indent);
+            out.push_str(name);
+            out.push_str(" {");
+            if children.is_empty() {
+                for (index, (key, value)) in attributes.iter().enumerate() {
+                    out.push_str(if index == 0 { " " } else { ", " });
+                    out.push_str(&format!("{key}: {value}"));
+                }
+                out.push_str(" }\n");
+                return;
+            }
+            out.push('\n');
+            for (key, value) in attributes {
+                out.push_str(&format!("{indent}    {key}: {value},\n"));
+            }
+            for child in children {
+                write_node(out, child, depth + 1);
+            }
+            out.push_str(&indent);
+            out.push_str("}\n");
+        }
+        Node::Text(text) => {
+            out.push_str(&indent);
+            out.push_str(&literal(text));
+            out.push('\n');
+        }
+        Node::Rsx(rsx) => {
+            out.push_str(rsx);
+            out.push('\n');
+        }
+        Node::Code(code) => {
+            out.push_str(&format!("{indent}CodeBlock {{ block: &{} }}\n", highlighted_code(code)));
+        }
+        Node::Group(blocks) => {
+            let blocks: Vec<_> = blocks.iter().map(highlighted_code).collect();
+            out.push_str(&format!("{indent}CodeGroup {{ blocks: &[{}] }}\n", blocks.join(", ")));
+        }
+    }
+}
+
+/// A `HighlightedCode` expression. It is a plain rust expression, so its strings aren't formatted.
+fn highlighted_code(code: &Code) -> String {
+    let lines: Vec<String> = code.lines.iter().map(|line| rust_string(line)).collect();
+    format!(
+        "HighlightedCode {{ info: {}, code: {}, lines: &[{}] }}",
+        rust_string(&code.info),
+        rust_string(&code.code),
+        lines.join(", ")
+    )
+}
+
+/// A string literal for rsx, which formats its strings, so braces are doubled
+fn literal(text: &str) -> String {
+    rust_string(text).replace('{', "{{").replace('}', "}}")
+}
+
+/// A rust string literal
+fn rust_string(text: &str) -> String {
+    let mut literal = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => {}
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// The url of the page a link to a markdown file of the book points to, or `None` for links that
+/// leave the docs or point into the current page
+fn resolve_link(destination: &str, location: &PageLocation) -> Option<String> {
+    let (path, fragment) = match destination.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (destination, None),
+    };
+    if path.starts_with('/') && !path.starts_with("//") {
+        return Some(destination.to_string());
+    }
+    if path.contains(':') || !path.ends_with(".md") {
+        return None;
+    }
+
+    let mut segments: Vec<&str> = location.source.split('/').collect();
+    segments.pop();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    let page = segments.join("/");
+    let page = page.trim_end_matches(".md");
+    let page = match page.strip_suffix("index") {
+        Some(dir) if dir.is_empty() || dir.ends_with('/') => dir.trim_end_matches('/'),
+        _ => page,
+    };
+
+    let mut url = location.book_url.to_string();
+    if !page.is_empty() {
+        url.push('/');
+        url.push_str(page);
+    }
+    if let Some(fragment) = fragment {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    Some(url)
+}
+
+/// Images of the docs live in `public/`, which is served from the root of the site
+fn resolve_image(destination: &str) -> String {
+    match destination.split_once("public/") {
+        Some((prefix, path)) if !prefix.contains(':') => format!("/{path}"),
+        _ => destination.to_string(),
+    }
+}
+
+#[cfg(test)]
+fn render_page(markdown: &str) -> RenderedMarkdown {
+    let location = PageLocation {
+        source: "reference/hooks.md",
+        book_url: "/learn/0.5",
+    };
+    render(markdown, &location, &Highlighter::new())
+}
+
+#[test]
+fn keeps_inline_html_together() {
+    let rendered = render_page("Press <kbd>Ctrl</kbd> + <kbd>`C`</kbd> to copy.<br>");
+    assert!(rendered
+        .rsx
+        .contains(r#"span { dangerous_inner_html: "<kbd>Ctrl</kbd>" }"#));
+    assert!(rendered.rsx.contains(r#"" + ""#));
+    assert!(rendered
+        .rsx
+        .contains(r#"span { dangerous_inner_html: "<kbd><code>C</code></kbd>" }"#));
+    assert!(rendered
+        .rsx
+        .contains(r#"span { dangerous_inner_html: "<br>" }"#));
+}
+
+#[test]
+fn counts_unclosed_tags() {
+    assert_eq!(unclosed_tags("<kbd>"), 1);
+    assert_eq!(unclosed_tags("<kbd>a</kbd>"), 0);
+    assert_eq!(unclosed_tags("<span class=\"a\"><b>"), 2);
+    assert_eq!(unclosed_tags("<br><img src=\"a.png\"/><!-- comment -->"), 0);
+}
+
+#[test]
+fn renders_headings_links_and_code() {
+    let markdown = "## Hello `{World}`\n\n\
+        See [the guide](../guide/index.md#state) or [rust](https://www.rust-lang.org).\n\n\
+        ```rust, group=setup, filename=main.rs\nfn main() {}\n```\n\n\
+        ```toml, group=setup, filename=Cargo.toml\n[dependencies]\n```\n\n\
+        ```inject-dioxus\nDemoFrame { readme::App {} }\n```\n";
+    let rendered = render_page(markdown);
+
+    assert_eq!(
+        rendered.headings,
+        [(2, "Hello {World}".to_string(), "hello-world".to_string())]
+    );
+    assert!(rendered.rsx.contains(r#"id: "hello-world","#));
+    assert!(rendered.rsx.contains(r#""{{World}}""#));
+    assert!(rendered.rsx.contains(r#"to: "/learn/0.5/guide#state","#));
+    assert!(rendered
+        .rsx
+        .contains(r#"href: "https://www.rust-lang.org","#));
+    assert_eq!(rendered.rsx.matches("CodeGroup { blocks: &[").count(), 1);
+    assert!(!rendered.rsx.contains("CodeBlock {"));
+    assert!(rendered.rsx.contains("\nDemoFrame { readme::App {} }\n"));
+}
+
+#[test]
+fn resolves_links_to_pages() {
+    let location = PageLocation {
+        source: "reference/hooks.md",
+        book_url: "/learn/0.5/pt-br",
+    };
+    let resolve = |destination| resolve_link(destination, &location);
+    assert_eq!(
+        resolve("context.md").as_deref(),
+        Some("/learn/0.5/pt-br/reference/context")
+    );
+    assert_eq!(resolve("../index.md").as_deref(), Some("/learn/0.5/pt-br"));
+    assert_eq!(resolve("/blog").as_deref(), Some("/blog"));
+    assert_eq!(resolve("#state"), None);
+    assert_eq!(resolve("https://dioxuslabs.com/a.md"), None);
+}
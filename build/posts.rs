@@ -0,0 +1,259 @@
+//! Compiles the blog posts in `posts/` into Rust.
+//!
+//! Every markdown file directly inside `posts/` is a post. It starts with a front-matter block:
+//!
+//! ```md
+//! ---
+//! title: Announcing Dioxus 0.5
+//! date: March 21, 2024
+//! category: Release Notes
+//! description: A signal rewrite, zero unsafe, no lifetimes, unified launch, and more!
+//! slug: release-050
+//! ---
+//! ```
+//!
+//! The rest of the file is rendered to HTML with the same syntect theme the docs use. The output is a
+//! `POSTS` table, a `BlogRoute` enum with one route per post and a component for each of those routes.
+//! It is included by `src/components/blog/mod.rs`.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use chrono::Datelike;
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag};
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+
+const POSTS_DIR: &str = "posts";
+pub(crate) const SYNTECT_THEME: &str = "base16-ocean.dark";
+
+struct Post {
+    source: PathBuf,
+    title: String,
+    date: String,
+    published: chrono::NaiveDate,
+    category: String,
+    description: String,
+    slug: String,
+    html: String,
+}
+
+pub(crate) fn build(out_dir: &Path) {
+    println!("cargo:rerun-if-changed={POSTS_DIR}");
+
+    let mut posts = Vec::new();
+    for entry in std::fs::read_dir(POSTS_DIR).expect("failed to read the posts directory") {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(false, |ext| ext == "md") {
+            println!("cargo:rerun-if-changed={}", path.display());
+            posts.push(load_post(&path));
+        }
+    }
+
+    // Newest posts first
+    posts.sort_by(|a, b| b.published.cmp(&a.published));
+
+    std::fs::write(out_dir.join("posts.rs"), generate(&posts)).unwrap();
+}
+
+fn load_post(path: &Path) -> Post {
+    let contents = std::fs::read_to_string(path).unwrap();
+
+    let Some(rest) = contents.strip_prefix("---") else {
+        fail(path, "posts must start with a `---` front-matter block")
+    };
+    let Some((front_matter, markdown)) = rest.split_once("\n---") else {
+        fail(path, "unterminated front-matter block")
+    };
+
+    let field = |name: &str| -> String {
+        front_matter
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(key, _)| key.trim() == name)
+            .map(|(_, value)| value.trim().to_string())
+            .unwrap_or_else(|| fail(path, &format!("missing `{name}` in the front-matter")))
+    };
+
+    let title = field("title");
+    let date = field("date");
+    let category = field("category");
+    let description = field("description");
+    let slug = field("slug");
+
+    let published = ["%b %d, %Y", "%b %d %Y"]
+        .iter()
+        .find_map(|format| chrono::NaiveDate::parse_from_str(&date, format).ok())
+        .unwrap_or_else(|| fail(path, &format!("could not parse the date {date:?}")));
+
+    if slug.is_empty() || !slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        fail(
+            path,
+            &format!("the slug {slug:?} may only contain ascii letters, digits and dashes"),
+        )
+    }
+
+    Post {
+        source: path.to_path_buf(),
+        title,
+        date,
+        published,
+        category,
+        description,
+        slug,
+        html: render_markdown(markdown.trim_start_matches(|c| c != '\n')),
+    }
+}
+
+fn fail(path: &Path, message: &str) -> ! {
+    panic!("{}: {message}", path.display())
+}
+
+/// Render markdown to HTML, highlighting code blocks with syntect and giving every heading an id
+fn render_markdown(markdown: &str) -> String {
+    render_markdown_with(markdown, |events| events)
+}
+
+/// Like [`render_markdown`], but the events of the parser go through `map` first, which can rewrite,
+/// drop or merge them
+pub(crate) fn render_markdown_with<'a, I: Iterator<Item = Event<'a>>>(
+    markdown: &'a str,
+    map: impl FnOnce(Parser<'a, 'a>) -> I,
+) -> String {
+    let syntaxes = SyntaxSet::load_defaults_newlines();
+    let themes = ThemeSet::load_defaults();
+    let theme = &themes.themes[SYNTECT_THEME];
+
+    let mut events = Vec::new();
+    let mut code_block: Option<(String, String)> = None;
+    let mut heading: Option<(String, Vec<Event>)> = None;
+
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+
+    for event in map(Parser::new_ext(markdown, options)) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let lang = match kind {
+                    CodeBlockKind::Fenced(lang) => lang.split(',').next().unwrap_or("").to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                code_block = Some((lang, String::new()));
+            }
+            Event::Text(text) if code_block.is_some() => {
+                code_block.as_mut().unwrap().1.push_str(&text);
+            }
+            Event::End(Tag::CodeBlock(_)) => {
+                let (lang, code) = code_block.take().unwrap();
+                let syntax = syntaxes
+                    .find_syntax_by_token(&lang)
+                    .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+                let html =
+                    syntect::html::highlighted_html_for_string(&code, &syntaxes, syntax, theme)
+                        .unwrap();
+                events.push(Event::Html(html.into()));
+            }
+            Event::Start(Tag::Heading(..)) => heading = Some((String::new(), Vec::new())),
+            Event::End(Tag::Heading(level, ..)) => {
+                let (text, inner) = heading.take().unwrap();
+                events.push(Event::Html(
+                    format!(r#"<{level} id="{}">"#, slugify(&text)).into(),
+                ));
+                events.extend(inner);
+                events.push(Event::Html(format!("</{level}>\n").into()));
+            }
+            event => match &mut heading {
+                Some((text, inner)) => {
+                    if let Event::Text(t) | Event::Code(t) = &event {
+                        text.push_str(t);
+                    }
+                    inner.push(event);
+                }
+                None => events.push(event),
+            },
+        }
+    }
+
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events.into_iter());
+    html
+}
+
+/// Turn a heading into an anchor id, e.g. "What's new?" -> "whats-new"
+pub(crate) fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string()
+}
+
+/// Turn a slug into the name of the route variant, e.g. "release-050" -> "PostRelease050"
+fn variant_name(slug: &str) -> String {
+    prefixed_variant_name("Post", slug)
+}
+
+/// Turn a slug into a type name that starts with `prefix`
+pub(crate) fn prefixed_variant_name(prefix: &str, slug: &str) -> String {
+    let mut name = String::from(prefix);
+    for word in slug.split('-') {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.extend(first.to_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+    name
+}
+
+fn generate(posts: &[Post]) -> String {
+    let mut out = String::new();
+
+    out.push_str("pub(crate) const POSTS: &[BlogPost] = &[\n");
+    for post in posts {
+        _ = writeln!(out, "    BlogPost {{");
+        _ = writeln!(out, "        category: {:?},", post.category);
+        _ = writeln!(out, "        date: {:?},", post.date);
+        _ = writeln!(
+            out,
+            "        published: ({}, {}, {}),",
+            post.published.year(),
+            post.published.month(),
+            post.published.day()
+        );
+        _ = writeln!(out, "        title: {:?},", post.title);
+        _ = writeln!(out, "        description: {:?},", post.description);
+        _ = writeln!(out, "        link: \"/blog/{}/\",", post.slug);
+        _ = writeln!(out, "        source: {:?},", post.source.display().to_string());
+        _ = writeln!(out, "        content: {:?},", post.html);
+        _ = writeln!(out, "    }},");
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("#[derive(Clone, Routable, PartialEq, Eq, Serialize, Deserialize, Debug)]\n");
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str("pub(crate) enum BlogRoute {\n");
+    out.push_str("    #[route(\"/\")]\n");
+    out.push_str("    BlogList {},\n");
+    for post in posts {
+        _ = writeln!(out, "    #[route(\"/{}\")]", post.slug);
+        _ = writeln!(out, "    {} {{}},", variant_name(&post.slug));
+    }
+    out.push_str("}\n\n");
+
+    for (index, post) in posts.iter().enumerate() {
+        _ = writeln!(out, "#[component]");
+        _ = writeln!(out, "fn {}() -> Element {{", variant_name(&post.slug));
+        _ = writeln!(out, "    rsx! {{ SinglePost {{ post: POSTS[{index}] }} }}");
+        _ = writeln!(out, "}}\n");
+    }
+
+    out
+}
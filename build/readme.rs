@@ -0,0 +1,397 @@
+//! Makes the READMEs of awesome entries safe to put on the site.
+//!
+//! READMEs are written by anyone, so their raw HTML is cut down to a list of harmless tags and
+//! attributes, urls with a scheme other than http(s) and mailto are dropped, and relative links are
+//! pointed at the repo on GitHub. The module only needs pulldown-cmark, so `src/main.rs` also
+//! compiles it for its tests.
+
+use pulldown_cmark::{Event, LinkType, Tag};
+use std::fmt::Write;
+
+/// Tags READMEs may use. Anything else is dropped, but its content is kept.
+const ALLOWED_TAGS: &[&str] = &[
+    "a", "abbr", "b", "blockquote", "br", "code", "dd", "del", "details", "div", "dl", "dt", "em",
+    "h1", "h2", "h3", "h4", "h5", "h6", "hr", "i", "img", "kbd", "li", "ol", "p", "picture", "pre",
+    "s", "source", "span", "strong", "sub", "summary", "sup", "table", "tbody", "td", "tfoot", "th",
+    "thead", "tr", "ul",
+];
+/// Tags that are dropped along with everything inside them
+const DROPPED_TAGS: &[&str] = &[
+    "script", "style", "iframe", "frame", "object", "embed", "noscript", "template", "textarea",
+    "select", "svg", "math",
+];
+const ALLOWED_ATTRIBUTES: &[&str] = &[
+    "align", "alt", "colspan", "height", "href", "media", "rowspan", "src", "srcset", "title",
+    "width",
+];
+
+/// Where the relative links of a README point
+#[derive(Clone)]
+pub(crate) struct Repo {
+    pub(crate) username: String,
+    pub(crate) repo: String,
+}
+
+impl Repo {
+    fn link_base(&self) -> String {
+        format!("https://github.com/{}/{}/blob/HEAD/", self.username, self.repo)
+    }
+
+    fn image_base(&self) -> String {
+        format!("https://raw.githubusercontent.com/{}/{}/HEAD/", self.username, self.repo)
+    }
+}
+
+/// Sanitize the raw HTML and resolve the urls of the events of a README
+pub(crate) fn sanitize_readme<'a>(
+    events: impl Iterator<Item = Event<'a>>,
+    repo: Repo,
+) -> impl Iterator<Item = Event<'a>> {
+    merge_html(events).map(move |event| match event {
+        Event::Html(html) => Event::Html(sanitize_html(&html, &repo).into()),
+        Event::Start(tag) => Event::Start(rewrite_tag(tag, &repo)),
+        Event::End(tag) => Event::End(rewrite_tag(tag, &repo)),
+        event => event,
+    })
+}
+
+/// The parser hands out a block of raw HTML line by line. Join the lines, so a tag that spans
+/// several of them is sanitized as a whole.
+fn merge_html<'a>(events: impl Iterator<Item = Event<'a>>) -> impl Iterator<Item = Event<'a>> {
+    let mut events = events.peekable();
+    std::iter::from_fn(move || {
+        let mut html = match events.next()? {
+            Event::Html(html) => html.into_string(),
+            event => return Some(event),
+        };
+        while let Some(Event::Html(next)) = events.next_if(|event| matches!(event, Event::Html(_))) {
+            html.push_str(&next);
+        }
+        Some(Event::Html(html.into()))
+    })
+}
+
+fn rewrite_tag<'a>(tag: Tag<'a>, repo: &Repo) -> Tag<'a> {
+    match tag {
+        // Emails are rendered with `mailto:` in front of them
+        Tag::Link(LinkType::Email, url, title) => Tag::Link(LinkType::Email, url, title),
+        Tag::Link(kind, url, title) => {
+            let url = resolve_url(&url, &repo.link_base()).unwrap_or_default();
+            Tag::Link(kind, url.into(), title)
+        }
+        Tag::Image(kind, url, title) => {
+            let url = resolve_url(&url, &repo.image_base()).unwrap_or_default();
+            Tag::Image(kind, url.into(), title)
+        }
+        tag => tag,
+    }
+}
+
+/// Make a url from a README absolute. Returns `None` for schemes other than http(s) and mailto,
+/// like `javascript:`. Browsers ignore tabs and newlines in urls and control characters around
+/// them, so those are removed before the scheme is checked.
+fn resolve_url(url: &str, base: &str) -> Option<String> {
+    let url: String = url
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    let url = url.trim_matches(|c: char| c <= ' ');
+    if url.starts_with('#') || url.starts_with("//") {
+        return Some(url.to_string());
+    }
+    if let Some(end) = url.find(|c| matches!(c, ':' | '/' | '?' | '#')) {
+        if url[end..].starts_with(':') {
+            let scheme = url[..end].to_ascii_lowercase();
+            return matches!(scheme.as_str(), "http" | "https" | "mailto").then(|| url.to_string());
+        }
+    }
+    let path = url.trim_start_matches("./").trim_start_matches('/');
+    Some(format!("{base}{path}"))
+}
+
+/// Resolve every url in a `srcset` like `logo.png 1x, logo@2x.png 2x`
+fn resolve_srcset(srcset: &str, base: &str) -> Option<String> {
+    let candidates = srcset
+        .split(',')
+        .map(|candidate| {
+            let candidate = candidate.trim();
+            let (url, descriptor) = candidate.split_once(' ').unwrap_or((candidate, ""));
+            let url = resolve_url(url, base)?;
+            Some(format!("{url} {descriptor}").trim_end().to_string())
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(candidates.join(", "))
+}
+
+/// Keep only the allowed tags and attributes of a piece of raw HTML
+fn sanitize_html(html: &str, repo: &Repo) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + "-->".len()..]);
+            continue;
+        }
+        let Some((tag, len)) = HtmlTag::parse(rest) else {
+            // A `<` that doesn't start a tag, like in `a < b`
+            output.push_str("&lt;");
+            rest = &rest[1..];
+            continue;
+        };
+        rest = &rest[len..];
+
+        if DROPPED_TAGS.contains(&tag.name.as_str()) {
+            if !tag.closing {
+                rest = skip_past_closing_tag(rest, &tag.name);
+            }
+        } else if ALLOWED_TAGS.contains(&tag.name.as_str()) {
+            output.push_str(&tag.render(repo));
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// The rest of the html after `</name ...>`, or nothing if the tag is never closed
+fn skip_past_closing_tag<'a>(html: &'a str, name: &str) -> &'a str {
+    let closing = format!("</{name}");
+    let Some(start) = html.to_ascii_lowercase().find(&closing) else {
+        return "";
+    };
+    let after = &html[start..];
+    after.find('>').map_or("", |end| &after[end + 1..])
+}
+
+/// Decode the character references in an attribute value, so `&#106;avascript:` is checked as the
+/// `javascript:` the browser sees
+fn decode_entities(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        let decoded = if let Some(number) = rest.strip_prefix('#') {
+            let (digits, radix) = match number.strip_prefix(|c| c == 'x' || c == 'X') {
+                Some(hex) => (hex, 16),
+                None => (number, 10),
+            };
+            let len = digits
+                .find(|c: char| !c.is_digit(radix))
+                .unwrap_or(digits.len());
+            u32::from_str_radix(&digits[..len], radix)
+                .ok()
+                .map(|code| char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+                .map(|c| (c, rest.len() - digits.len() + len))
+        } else {
+            ENTITIES.iter().find_map(|(name, c)| {
+                rest.strip_prefix(name)?;
+                Some((*c, name.len()))
+            })
+        };
+        match decoded {
+            Some((c, len)) => {
+                output.push(c);
+                rest = &rest[len..];
+                // Numeric references may leave out the `;`
+                rest = rest.strip_prefix(';').unwrap_or(rest);
+            }
+            None => output.push('&'),
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// The named references that matter for urls and the ones READMEs use in text
+const ENTITIES: &[(&str, char)] = &[
+    ("amp;", '&'),
+    ("lt;", '<'),
+    ("gt;", '>'),
+    ("quot;", '"'),
+    ("apos;", '\''),
+    ("colon;", ':'),
+    ("sol;", '/'),
+    ("Tab;", '\t'),
+    ("NewLine;", '\n'),
+    ("nbsp;", '\u{a0}'),
+    ("copy;", '©'),
+];
+
+struct HtmlTag {
+    name: String,
+    closing: bool,
+    attributes: Vec<(String, Option<String>)>,
+}
+
+impl HtmlTag {
+    /// Parse the tag at the start of `html`, returning it and its length
+    fn parse(html: &str) -> Option<(Self, usize)> {
+        let mut rest = html.strip_prefix('<')?;
+        let closing = rest.starts_with('/');
+        rest = rest.trim_start_matches('/');
+
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let name = rest[..name_len].to_ascii_lowercase();
+        rest = &rest[name_len..];
+
+        let mut attributes = Vec::new();
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+            if let Some(after) = rest.strip_prefix('>') {
+                let len = html.len() - after.len();
+                return Some((Self { name, closing, attributes }, len));
+            }
+            let name_len = rest.find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))?;
+            if name_len == 0 {
+                return None;
+            }
+            let attribute = rest[..name_len].to_ascii_lowercase();
+            rest = rest[name_len..].trim_start();
+
+            let Some(value) = rest.strip_prefix('=') else {
+                attributes.push((attribute, None));
+                continue;
+            };
+            let value = value.trim_start();
+            let (value, after) = match value.chars().next()? {
+                quote @ ('"' | '\'') => {
+                    let end = value[1..].find(quote)? + 1;
+                    (&value[1..end], &value[end + 1..])
+                }
+                _ => {
+                    let end = value.find(|c: char| c.is_whitespace() || c == '>')?;
+                    (&value[..end], &value[end..])
+                }
+            };
+            attributes.push((attribute, Some(decode_entities(value))));
+            rest = after;
+        }
+    }
+
+    fn render(&self, repo: &Repo) -> String {
+        if self.closing {
+            return format!("</{}>", self.name);
+        }
+        let mut html = format!("<{}", self.name);
+        for (name, value) in &self.attributes {
+            if !ALLOWED_ATTRIBUTES.contains(&name.as_str()) {
+                continue;
+            }
+            let value = match (name.as_str(), value) {
+                (_, None) => {
+                    _ = write!(html, " {name}");
+                    continue;
+                }
+                ("href", Some(url)) => resolve_url(url, &repo.link_base()),
+                ("src", Some(url)) => resolve_url(url, &repo.image_base()),
+                ("srcset", Some(urls)) => resolve_srcset(urls, &repo.image_base()),
+                (_, Some(value)) => Some(value.clone()),
+            };
+            if let Some(value) = value {
+                let value = value
+                    .replace('&', "&amp;")
+                    .replace('"', "&quot;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+                _ = write!(html, r#" {name}="{value}""#);
+            }
+        }
+        html.push('>');
+        html
+    }
+}
+
+#[cfg(test)]
+fn render(markdown: &str) -> String {
+    let repo = Repo {
+        username: "user".to_string(),
+        repo: "project".to_string(),
+    };
+    let events = sanitize_readme(pulldown_cmark::Parser::new(markdown), repo);
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(&mut html, events);
+    html
+}
+
+#[test]
+fn drops_script_urls_from_markdown() {
+    let html = render(concat!(
+        "[a](javascript:alert(1)) [b](JavaScript:alert(1)) [c](java&#115;cript:alert(1)) ",
+        "[d](data:text/html,hi) ![e](javascript:alert(1))\n\n",
+        "[docs](docs/guide.md) [site](https://dioxuslabs.com) ![logo](./logo.png)",
+    ));
+    assert!(!html.to_lowercase().contains("script:"), "{}", html);
+    assert!(!html.contains("data:"), "{}", html);
+    assert!(html.contains(r#"href="https://github.com/user/project/blob/HEAD/docs/guide.md""#));
+    assert!(html.contains(r#"href="https://dioxuslabs.com""#));
+    assert!(html.contains(r#"src="https://raw.githubusercontent.com/user/project/HEAD/logo.png""#));
+}
+
+#[test]
+fn drops_script_urls_from_html() {
+    let repo = Repo {
+        username: "user".to_string(),
+        repo: "project".to_string(),
+    };
+    for html in [
+        r#"<a href="javascript:alert(1)">x</a>"#,
+        r#"<a href=" JAVASCRIPT:alert(1)">x</a>"#,
+        r#"<a href="java&#x09;script:alert(1)">x</a>"#,
+        r#"<a href="&#106;avascript&#58;alert(1)">x</a>"#,
+        r#"<a href="&#x6A&#x61&#x76&#x61script&colon;alert(1)">x</a>"#,
+        r#"<img src="javascript&colon;alert(1)">"#,
+        r#"<img srcset="logo.png 1x, javascript:alert(1) 2x">"#,
+    ] {
+        let sanitized = sanitize_html(html, &repo);
+        assert!(!sanitized.to_lowercase().contains("script"), "{} -> {}", html, sanitized);
+    }
+    assert_eq!(
+        sanitize_html(r#"<img srcset="logo.png 1x, big.png 2x" alt="a &amp; b">"#, &repo),
+        concat!(
+            r#"<img srcset="https://raw.githubusercontent.com/user/project/HEAD/logo.png 1x, "#,
+            r#"https://raw.githubusercontent.com/user/project/HEAD/big.png 2x" alt="a &amp; b">"#
+        )
+    );
+}
+
+#[test]
+fn drops_event_handlers_and_script_tags() {
+    let repo = Repo {
+        username: "user".to_string(),
+        repo: "project".to_string(),
+    };
+    assert_eq!(
+        sanitize_html(
+            r#"<img src="a.png" onerror="alert(1)"><p/onclick=alert(1) ONMOUSEOVER='x'>hi</p><script>alert(1)</script><style>p{}</style>"#,
+            &repo
+        ),
+        r#"<img src="https://raw.githubusercontent.com/user/project/HEAD/a.png"><p>hi</p>"#
+    );
+}
+
+#[test]
+fn sanitizes_tags_that_span_lines() {
+    let html = render(concat!(
+        "<p align=\"center\">\n",
+        "  <img\n",
+        "    src=\"logo.png\"\n",
+        "    onerror=\"alert(1)\"\n",
+        "  >\n",
+        "</p>\n\n",
+        "<script>\n",
+        "alert(1)\n",
+        "</script>\n",
+    ));
+    assert!(!html.contains("onerror"), "{}", html);
+    assert!(!html.contains("alert"), "{}", html);
+    assert!(html.contains(r#"<img src="https://raw.githubusercontent.com/user/project/HEAD/logo.png">"#));
+}
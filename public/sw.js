@@ -0,0 +1,71 @@
+// Keeps a copy of the app shell and the search index so the site, and search, work offline after
+// the first visit.
+//
+// Pages, the wasm, scripts and the chunks of the search index are fetched from the network first and
+// only fall back to the cache when offline: every deploy changes them together, and a page of a new
+// deploy can't run the wasm of an old one. Styles and images are served from the cache and
+// refreshed in the background.
+//
+// Bump the version when the caching changes, so the caches of older service workers are dropped.
+const CACHE = "dioxus-docsite-v2";
+
+// Requests that must match the deploy of the page that makes them
+const NETWORK_FIRST = [".js", ".wasm", ".bin"];
+
+self.addEventListener("install", () => {
+  self.skipWaiting();
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key)))
+      )
+      .then(() => self.clients.claim())
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+  if (request.method !== "GET" || url.origin !== self.location.origin) {
+    return;
+  }
+
+  if (request.mode === "navigate" || NETWORK_FIRST.some((ext) => url.pathname.endsWith(ext))) {
+    event.respondWith(networkFirst(request));
+  } else {
+    event.respondWith(staleWhileRevalidate(request));
+  }
+});
+
+async function networkFirst(request) {
+  const cache = await caches.open(CACHE);
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      cache.put(request, response.clone());
+    }
+    return response;
+  } catch (err) {
+    // Every page boots the same app, so any cached page can render a route we haven't visited yet
+    const fallback = request.mode === "navigate" ? await cache.match("/") : undefined;
+    return (await cache.match(request)) || fallback || Response.error();
+  }
+}
+
+async function staleWhileRevalidate(request) {
+  const cache = await caches.open(CACHE);
+  const cached = await cache.match(request);
+  const refresh = fetch(request)
+    .then((response) => {
+      if (response.ok) {
+        cache.put(request, response.clone());
+      }
+      return response;
+    })
+    .catch(() => cached || Response.error());
+  return cached || refresh;
+}
@@ -1,15 +1,25 @@
 use crate::*;
 use dioxus::prelude::*;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use wasm_bindgen::prelude::wasm_bindgen;
 
+mod page;
+mod query;
+pub(crate) mod snapshot;
+mod stars;
+pub(crate) mod validate;
+pub(crate) use page::AwesomeRoute;
+pub(crate) use query::AwesomeQuery;
+use query::Sort;
+use snapshot::SNAPSHOT;
+
 const ITEM_LIST_LINK: &str =
     "https://raw.githubusercontent.com/DioxusLabs/awesome-dioxus/master/awesome.json";
-const STAR_CACHE_NAME: &str = "STARS-";
 
-#[derive(Props, Clone, serde::Deserialize, PartialEq)]
-struct Item {
+#[derive(Props, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub(crate) struct Item {
     name: String,
     description: String,
     r#type: AwesomeType,
@@ -22,21 +32,80 @@ struct Item {
     /// Optional external link
     /// Replaces the auto-generated github link with an external link.
     link: Option<String>,
+
+    /// Stars of the GitHub repo when the snapshot was taken
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stars: Option<u64>,
+
+    /// Platforms the project works on, like "Web" or "Desktop". Prebuild fills them in from the
+    /// README for entries that don't list them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    platforms: Vec<String>,
+}
+
+impl Item {
+    /// The slug of the item's page under `/awesome/`, e.g. "Dioxus Free Icons" ->
+    /// "dioxus-free-icons". `build/awesome.rs` generates the routes with the same slugs.
+    pub(crate) fn slug(&self) -> String {
+        let mut slug = String::new();
+        for c in self.name.chars() {
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        slug.trim_end_matches('-').to_string()
+    }
+
+    /// The url of the item's page, if the site was built with one. Entries added to the list after
+    /// the build don't have a page, and neither do entries whose slug an earlier entry took.
+    fn page(&self) -> Option<String> {
+        let slug = self.slug();
+        let owner = SNAPSHOT.items.iter().find(|item| item.slug() == slug)?;
+        (!slug.is_empty() && owner.name == self.name).then(|| format!("/awesome/{slug}"))
+    }
+
+    /// Where the project lives: its own link, or else its GitHub repo
+    fn url(&self) -> String {
+        match (&self.link, &self.github) {
+            (Some(link), _) => link.clone(),
+            (None, Some(github)) => {
+                format!("https://github.com/{}/{}", github.username, github.repo)
+            }
+            (None, None) => "https://dioxuslabs.com/404".to_string(),
+        }
+    }
 }
 
-#[derive(Clone, serde::Deserialize, PartialEq)]
+#[derive(Clone, serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
 enum AwesomeType {
     Awesome,
     MadeWith,
 }
 
-#[derive(Default, Clone, serde::Deserialize, PartialEq)]
+impl AwesomeType {
+    fn slug(&self) -> &'static str {
+        match self {
+            Self::Awesome => "awesome",
+            Self::MadeWith => "made-with",
+        }
+    }
+
+    fn from_slug(slug: &str) -> Option<Self> {
+        [Self::Awesome, Self::MadeWith]
+            .into_iter()
+            .find(|r#type| r#type.slug() == slug)
+    }
+}
+
+#[derive(Default, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
 struct GithubInfo {
     username: String,
     repo: String,
 }
 
-#[derive(Clone, Copy, serde::Deserialize, PartialEq)]
+#[derive(Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug)]
 enum Category {
     Misc,
     Util,
@@ -50,9 +119,47 @@ enum Category {
     App,
 }
 
+impl Category {
+    const ALL: [Category; 9] = [
+        Self::Misc,
+        Self::Util,
+        Self::Logging,
+        Self::Components,
+        Self::Example,
+        Self::Styling,
+        Self::Deployment,
+        Self::Renderer,
+        Self::App,
+    ];
+
+    fn slug(self) -> &'static str {
+        match self {
+            Self::Misc => "misc",
+            Self::Util => "util",
+            Self::Logging => "logging",
+            Self::Components => "components",
+            Self::Example => "example",
+            Self::Styling => "styling",
+            Self::Deployment => "deployment",
+            Self::Renderer => "renderer",
+            Self::App => "app",
+        }
+    }
+
+    fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.slug() == slug)
+    }
+}
+
 impl Display for Category {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let converted = match self {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Category {
+    fn name(self) -> &'static str {
+        match self {
             Self::Misc => "📎 Misc",
             Self::Util => "🧰 Util",
             Self::Logging => "📡 Logging",
@@ -62,61 +169,80 @@ impl Display for Category {
             Self::Deployment => "⚙️ Deployment",
             Self::Renderer => "🎥 Renderer",
             Self::App => "🚀 App",
-        };
-
-        write!(f, "{converted}")
+        }
     }
 }
 
+#[cfg(feature = "server")]
 #[derive(serde::Deserialize)]
 struct StarsResponse {
     stargazers_count: u64,
 }
 
 #[component]
-pub(crate) fn Awesome() -> Element {
+pub(crate) fn Awesome(query: AwesomeQuery) -> Element {
     rsx! {
         div { class: "bg-white dark:bg-ideblack mx-auto max-w-screen-lg",
-            AwesomeInner {}
+            AwesomeInner { query }
         }
     }
 }
 
 #[component]
-pub(crate) fn AwesomeInner() -> Element {
-    let items = use_resource(move || async move {
+pub(crate) fn AwesomeInner(query: AwesomeQuery) -> Element {
+    // The snapshot renders right away, the list on GitHub replaces it once it loads
+    let refreshed = use_resource(move || async move {
+        if cfg!(not(feature = "web")) {
+            return None;
+        }
+
         let req = match reqwest::get(ITEM_LIST_LINK).await {
             Ok(r) => r,
-            Err(e) => return Err(e.to_string()),
+            Err(e) => return Some(Err(e.to_string())),
         };
 
-        let items = match req.json::<Vec<Item>>().await {
-            Ok(i) => i,
-            Err(e) => return Err(e.to_string()),
+        let json = match req.text().await {
+            Ok(json) => json,
+            Err(e) => return Some(Err(e.to_string())),
         };
 
-        Ok(items)
+        // Entries that don't parse are left out rather than breaking the page
+        Some(validate::parse_items(&json).map(|(items, _)| items))
     });
 
-    let mut search = use_signal(|| "".to_string());
-
-    match &*items.read_unchecked() {
-        Some(Ok(items)) => {
-            to_owned![items];
-            items.sort_by(|a, b| {
-                b.category
-                    .to_string()
-                    .to_lowercase()
-                    .cmp(&a.category.to_string().to_lowercase())
-            });
-            let items: Vec<Item> = items
-                .into_iter()
-                .filter(|i| {
-                    i.name
-                        .to_lowercase()
-                        .contains(&search.read().to_lowercase())
-                })
-                .collect();
+    // Stars fresher than the snapshot's, for every repo on the list in one go
+    let live_stars = use_resource(move || async move {
+        if cfg!(not(feature = "web")) || is_fresh(SNAPSHOT.generated as f64) {
+            return HashMap::new();
+        }
+        let repos = match &*refreshed.read() {
+            // Wait for the list, it may have repos the snapshot doesn't
+            None => return HashMap::new(),
+            Some(Some(Ok(items))) => github_repos(items),
+            Some(_) => github_repos(&SNAPSHOT.items),
+        };
+        stars::fetch_all(repos).await
+    });
+
+    // Contributors can preview their own awesome.json with `?source=local`
+    let local_file = use_signal(|| None::<LocalFile>);
+
+    // Until the list loads, and when prerendering, the page is the snapshot. The server and the
+    // client render the same thing that way, even if the snapshot is empty.
+    let items = match &*refreshed.read_unchecked() {
+        _ if query.local => Ok(local_file().map(|file| file.items).unwrap_or_default()),
+        Some(Some(Ok(items))) => Ok(with_snapshot_stars(items.clone())),
+        Some(Some(Err(e))) if SNAPSHOT.items.is_empty() => Err(e.clone()),
+        _ => Ok(SNAPSHOT.items.clone()),
+    };
+
+    match &items {
+        Ok(items) => {
+            let items = with_live_stars(items.clone(), live_stars.read().as_ref());
+            let items = query.apply(&items);
+            let show_awesome = query.r#type != Some(AwesomeType::MadeWith);
+            let show_made_with = query.r#type != Some(AwesomeType::Awesome);
+            let search = query.search.clone();
 
             rsx!(
                 section { class: "dark:bg-ideblack bg-white w-full pt-4 md:pt-24 pb-10",
@@ -149,46 +275,59 @@ pub(crate) fn AwesomeInner() -> Element {
                                 class: "w-full text-center p-4 rounded-lg text-gray-300 bg-gray-100",
                                 placeholder: "Looking for something specific?",
                                 value: "{search}",
-                                oninput: move |evt| search.set(evt.value())
+                                oninput: {
+                                    let query = query.clone();
+                                    move |evt: FormEvent| {
+                                        set_query(AwesomeQuery { search: evt.value(), ..query.clone() })
+                                    }
+                                }
                             }
                         }
+                        if query.local {
+                            LocalPreview { file: local_file }
+                        }
+                        AwesomeFilters { query: query.clone() }
                     }
                 }
-                section { class: "dark:bg-ideblack w-full pb-24",
-                    div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-6 container mx-auto px-2 max-w-screen-1g",
-                        for item in items.iter() {
-                            if let AwesomeType::Awesome = item.r#type {
-                                AwesomeItem { key: "{item.name}", item: item.clone() }
+                if show_awesome {
+                    section { class: "dark:bg-ideblack w-full pb-24",
+                        div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-6 container mx-auto px-2 max-w-screen-1g",
+                            for item in items.iter() {
+                                if let AwesomeType::Awesome = item.r#type {
+                                    AwesomeCard { key: "{item.name}", item: item.clone() }
+                                }
                             }
                         }
                     }
                 }
 
-                section { class: "dark:bg-ideblack w-full pb-2 md:pb-10",
-                    div { class: "container mx-auto max-w-screen-1g text-center",
-                        h1 {
-                            class: "text-[1.5em] md:text-[3.3em] font-bold tracking-tight dark:text-white text-ghdarkmetal mb-2 px-2",
-                            id: "made-with-dioxus",
-                            "Made with Dioxus"
-                        }
-                        p { class: "mx-auto text-xl text-gray-600 dark:text-gray-400 pb-10 px-2 max-w-screen-sm",
-                            "Real world uses of Dioxus."
+                if show_made_with {
+                    section { class: "dark:bg-ideblack w-full pb-2 md:pb-10",
+                        div { class: "container mx-auto max-w-screen-1g text-center",
+                            h1 {
+                                class: "text-[1.5em] md:text-[3.3em] font-bold tracking-tight dark:text-white text-ghdarkmetal mb-2 px-2",
+                                id: "made-with-dioxus",
+                                "Made with Dioxus"
+                            }
+                            p { class: "mx-auto text-xl text-gray-600 dark:text-gray-400 pb-10 px-2 max-w-screen-sm",
+                                "Real world uses of Dioxus."
+                            }
                         }
                     }
-                }
 
-                section { class: "bg-white dark:bg-ideblack w-full pb-24",
-                    div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-6 container mx-auto px-2 max-w-screen-1g",
-                        for item in items.iter() {
-                            if let AwesomeType::MadeWith = item.r#type {
-                                AwesomeItem { key: "{item.name}", item: item.clone() }
+                    section { class: "bg-white dark:bg-ideblack w-full pb-24",
+                        div { class: "grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4 gap-6 container mx-auto px-2 max-w-screen-1g",
+                            for item in items.iter() {
+                                if let AwesomeType::MadeWith = item.r#type {
+                                    AwesomeCard { key: "{item.name}", item: item.clone() }
+                                }
                             }
                         }
                     }
                 }
             )
         }
-        Some(Err(e)) => {
+        Err(e) => {
             rsx!(
                 section { class: "dark:bg-ideblack w-full pt-24 pb-96",
                     div { class: "container mx-auto max-w-screen-1g text-center animate-fadein-medium",
@@ -202,79 +341,190 @@ pub(crate) fn AwesomeInner() -> Element {
                 }
             )
         }
-        None => {
-            rsx!(
-                section { class: "dark:bg-ideblack w-full pt-24 pb-96",
-                    div { class: "container mx-auto max-w-screen-1g text-center animate-fadein-medium",
-                        p { class: "text-[3.3em] font-bold tracking-tight dark:text-white text-ghdarkmetal mb-2 px-2",
-                            "Loading..."
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct LocalFile {
+    name: String,
+    items: Vec<Item>,
+    errors: Vec<validate::EntryError>,
+}
+
+/// Pick a local awesome.json and list the problems with it
+#[component]
+fn LocalPreview(file: Signal<Option<LocalFile>>) -> Element {
+    let mut file = file;
+    let mut error = use_signal(|| None::<String>);
+
+    let load = move |evt: FormEvent| async move {
+        let Some(engine) = evt.files() else {
+            return;
+        };
+        let Some(name) = engine.files().into_iter().next() else {
+            return;
+        };
+        let Some(json) = engine.read_file_to_string(&name).await else {
+            error.set(Some(format!("couldn't read {name}")));
+            return;
+        };
+        match validate::parse_items(&json) {
+            Ok((items, errors)) => {
+                error.set(None);
+                file.set(Some(LocalFile { name, items, errors }));
+            }
+            Err(err) => {
+                file.set(None);
+                error.set(Some(format!("{name}: {err}")));
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "mx-2 lg:w-2/5 lg:mx-auto mt-4 p-4 rounded-lg border border-dashed border-gray-400 text-left text-gray-700 dark:text-gray-300",
+            p { class: "pb-2 font-semibold", "Preview your awesome.json" }
+            input { r#type: "file", accept: ".json", onchange: load }
+            if let Some(error) = error() {
+                p { class: "pt-2 text-red-500", "{error}" }
+            }
+            if let Some(file) = file() {
+                if file.errors.is_empty() {
+                    p { class: "pt-2 text-green-600",
+                        {format!("All {} entries of {} are valid", file.items.len(), file.name)}
+                    }
+                } else {
+                    p { class: "pt-2 text-red-500",
+                        {format!("{} problems in {}:", file.errors.len(), file.name)}
+                    }
+                    ul { class: "list-disc pl-6 text-sm",
+                        for error in file.errors.iter() {
+                            li { "{error}" }
                         }
                     }
                 }
-            )
+            }
         }
     }
 }
 
+fn set_query(query: AwesomeQuery) {
+    navigator().replace(Route::Awesome { query });
+}
+
 #[component]
-fn AwesomeItem(item: ReadOnlySignal<Item>) -> Element {
-    let stars = use_resource(move || {
-        async move {
-            let item = item.read();
-            let is_github = item.github.is_some();
-            let username = item
-                .github
-                .clone()
-                .unwrap_or(GithubInfo::default())
-                .username;
-            let repo = item.github.clone().unwrap_or(GithubInfo::default()).repo;
-            if is_github {
-                // Check cache
-                if let Some(stars) = get_stars(format!("{}{}/{}", STAR_CACHE_NAME, username, repo))
-                {
-                    return Some(stars);
-                }
+fn AwesomeFilters(query: AwesomeQuery) -> Element {
+    let types = [
+        (None, "Everything"),
+        (Some(AwesomeType::Awesome), "Awesome"),
+        (Some(AwesomeType::MadeWith), "Made with Dioxus"),
+    ];
+    let sorts = Sort::ALL.map(|sort| {
+        let label = match sort {
+            Sort::Category => "By category",
+            Sort::Stars => "Most stars",
+            Sort::Name => "By name",
+        };
+        (sort, label)
+    });
 
-                // Not in cache or expired, lets get from github
-                if let Ok(req) =
-                    reqwest::get(format!("https://api.github.com/repos/{username}/{repo}")).await
-                {
-                    if let Ok(res) = req.json::<StarsResponse>().await {
-                        // Add to cache
-
-                        set_stars(
-                            format!("{}{}/{}", STAR_CACHE_NAME, username, repo),
-                            res.stargazers_count as usize,
-                        );
-                        return Some(res.stargazers_count as usize);
+    rsx! {
+        div { class: "flex flex-col items-center gap-2 pt-4 px-2 text-gray-700 dark:text-gray-300",
+            div { class: "flex flex-row flex-wrap justify-center gap-2",
+                for (r#type , label) in types {
+                    FilterChip {
+                        label: label,
+                        active: query.r#type == r#type,
+                        onclick: {
+                            let query = query.clone();
+                            move |_| set_query(AwesomeQuery { r#type: r#type.clone(), ..query.clone() })
+                        }
+                    }
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-center gap-2",
+                FilterChip {
+                    label: "All categories",
+                    active: query.category.is_none(),
+                    onclick: {
+                        let query = query.clone();
+                        move |_| set_query(AwesomeQuery { category: None, ..query.clone() })
+                    }
+                }
+                for category in Category::ALL {
+                    FilterChip {
+                        label: category.name(),
+                        active: query.category == Some(category),
+                        onclick: {
+                            let query = query.clone();
+                            move |_| set_query(AwesomeQuery { category: Some(category), ..query.clone() })
+                        }
+                    }
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-center gap-2",
+                for (sort , label) in sorts {
+                    FilterChip {
+                        label: label,
+                        active: query.sort == sort,
+                        onclick: {
+                            let query = query.clone();
+                            move |_| set_query(AwesomeQuery { sort, ..query.clone() })
+                        }
                     }
                 }
             }
-
-            None
         }
-    });
+    }
+}
 
-    // Format stars text
-    let stars = match &*stars.value().read() {
-        Some(Some(v)) => format!("{} ⭐", v),
-        _ => "N/A ⭐".to_string(),
-    };
+/// Items fetched from GitHub don't have stars, so they get the ones from the snapshot
+fn with_snapshot_stars(mut items: Vec<Item>) -> Vec<Item> {
+    for item in &mut items {
+        if let Some(github) = &item.github {
+            item.stars = SNAPSHOT.stars(&github.username, &github.repo);
+        }
+    }
+    items
+}
 
-    let item = item.read();
+/// The repos of the items that are on GitHub
+fn github_repos(items: &[Item]) -> Vec<(String, String)> {
+    items
+        .iter()
+        .filter_map(|item| item.github.as_ref())
+        .map(|github| (github.username.clone(), github.repo.clone()))
+        .collect()
+}
 
-    // Figure out what link to use
-    let link = match &item.link {
-        Some(link) => link.clone(),
-        None => {
-            if let Some(github) = &item.github {
-                format!("https://github.com/{}/{}", github.username, github.repo)
-            } else {
-                "https://dioxuslabs.com/404".to_string()
+/// Replace the stars of the items with the ones the star proxy found
+fn with_live_stars(
+    mut items: Vec<Item>,
+    live: Option<&HashMap<(String, String), u64>>,
+) -> Vec<Item> {
+    let Some(live) = live else {
+        return items;
+    };
+    for item in &mut items {
+        if let Some(github) = &item.github {
+            let repo = (github.username.clone(), github.repo.clone());
+            if let Some(stars) = live.get(&repo) {
+                item.stars = Some(*stars);
             }
         }
+    }
+    items
+}
+
+#[component]
+fn AwesomeCard(item: ReadOnlySignal<Item>) -> Element {
+    let stars = match item.read().stars {
+        Some(stars) => format!("{} ⭐", stars),
+        None => "N/A ⭐".to_string(),
     };
 
+    let item = item.read();
+    let page = item.page().unwrap_or_else(|| item.url());
+
     let inner = rsx! {
         div {
             class: "flex flex-col h-full p-3 rounded hover:-translate-y-2 transition-transform duration-300 bg-white dark:bg-slate-800 shadow",
@@ -292,14 +542,11 @@ fn AwesomeItem(item: ReadOnlySignal<Item>) -> Element {
     };
 
     rsx! {
-        Link { to: NavigationTarget::<Route>::External(link), new_tab: true,
-            {inner}
-        }
+        Link { to: page, {inner} }
     }
 }
 
 #[wasm_bindgen(module = "/src/components/awesome/storage.js")]
 extern "C" {
-    pub(crate) fn get_stars(name: String) -> Option<usize>;
-    pub(crate) fn set_stars(name: String, stars: usize);
+    pub(crate) fn is_fresh(time: f64) -> bool;
 }
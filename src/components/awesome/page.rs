@@ -0,0 +1,67 @@
+//! A page for every entry of the awesome list, with the project's README. The routes and the rendered
+//! READMEs are generated from the snapshot by `build/awesome.rs`.
+
+use super::{Category, Item, SNAPSHOT};
+use crate::*;
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use serde::{Deserialize, Serialize};
+
+include!(concat!(env!("OUT_DIR"), "/awesome.rs"));
+
+#[component]
+fn AwesomeItemPage(slug: String) -> Element {
+    let Some(item) = SNAPSHOT.items.iter().find(|item| item.slug() == slug) else {
+        return rsx! { Err404 { segments: vec!["awesome".to_string(), slug] } };
+    };
+    let readme = AWESOME_READMES
+        .iter()
+        .find(|(readme_slug, _)| *readme_slug == slug)
+        .map(|(_, html)| *html);
+
+    rsx! {
+        section { class: "text-gray-600 body-font dark:bg-ideblack max-w-screen-md mx-auto pt-12 md:pt-24 px-2 font-light",
+            Link {
+                class: "text-sm text-gray-500 hover:text-sky-500 dark:hover:text-sky-400",
+                to: Route::Awesome { query: AwesomeQuery::default() },
+                "← Awesome stuff for Dioxus"
+            }
+            h1 { class: "pt-4 text-3xl md:text-5xl font-bold tracking-tight dark:text-white text-ghdarkmetal",
+                "{item.name}"
+            }
+            p { class: "pt-4 text-lg text-gray-700 dark:text-gray-400", "{item.description}" }
+            AwesomeItemFacts { item: item.clone() }
+            if let Some(readme) = readme {
+                article { class: "markdown-body pt-8", dangerous_inner_html: readme }
+            }
+        }
+    }
+}
+
+/// The category, stars and platforms of an item, and a link to the project
+#[component]
+fn AwesomeItemFacts(item: Item) -> Element {
+    rsx! {
+        div { class: "flex flex-row flex-wrap items-center gap-2 pt-4 text-sm text-gray-500 dark:text-gray-300",
+            if Category::App != item.category {
+                span { class: "px-3 py-1 rounded-full border border-gray-300 dark:border-gray-600 font-bold",
+                    "{item.category}"
+                }
+            }
+            if let Some(stars) = item.stars {
+                span { class: "px-3 py-1 rounded-full border border-gray-300 dark:border-gray-600 font-bold",
+                    "{stars} ⭐"
+                }
+            }
+            for platform in item.platforms.iter() {
+                span { class: "px-3 py-1 rounded-full bg-gray-100 dark:bg-gray-800", "{platform}" }
+            }
+            Link {
+                class: "ml-auto font-bold hover:text-sky-500 dark:hover:text-sky-400",
+                to: NavigationTarget::<Route>::External(item.url()),
+                new_tab: true,
+                if item.github.is_some() && item.link.is_none() { "View on GitHub →" } else { "Visit →" }
+            }
+        }
+    }
+}
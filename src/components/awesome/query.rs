@@ -0,0 +1,129 @@
+use super::{AwesomeType, Category, Item};
+use dioxus_router::routable::FromQuery;
+use std::fmt::{Display, Formatter};
+
+/// The filters of the awesome page. They are kept in the query of the url, like
+/// `/awesome?category=renderer&sort=stars`, so a filtered list can be shared.
+#[derive(Default, Clone, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct AwesomeQuery {
+    pub(super) search: String,
+    pub(super) category: Option<Category>,
+    pub(super) r#type: Option<AwesomeType>,
+    pub(super) sort: Sort,
+    /// Preview a local `awesome.json` instead of the published list
+    pub(super) local: bool,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub(super) enum Sort {
+    #[default]
+    Category,
+    Stars,
+    Name,
+}
+
+impl Sort {
+    pub(super) const ALL: [Sort; 3] = [Sort::Category, Sort::Stars, Sort::Name];
+
+    pub(super) fn slug(self) -> &'static str {
+        match self {
+            Sort::Category => "category",
+            Sort::Stars => "stars",
+            Sort::Name => "name",
+        }
+    }
+
+    pub(super) fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|sort| sort.slug() == slug)
+    }
+}
+
+impl AwesomeQuery {
+    pub(super) fn matches(&self, item: &Item) -> bool {
+        let search = self.search.trim().to_lowercase();
+        self.category.map_or(true, |category| item.category == category)
+            && self.r#type.as_ref().map_or(true, |r#type| &item.r#type == r#type)
+            && (search.is_empty()
+                || item.name.to_lowercase().contains(&search)
+                || item.description.to_lowercase().contains(&search))
+    }
+
+    /// The items that match the filters, in the selected order
+    pub(super) fn apply(&self, items: &[Item]) -> Vec<Item> {
+        let mut items: Vec<Item> = items.iter().filter(|item| self.matches(item)).cloned().collect();
+        match self.sort {
+            // In the order the filters list the categories, then by name
+            Sort::Category => items.sort_by_key(|item| {
+                let position = Category::ALL
+                    .iter()
+                    .position(|category| *category == item.category);
+                (position, item.name.to_lowercase())
+            }),
+            Sort::Stars => items.sort_by(|a, b| b.stars.cmp(&a.stars)),
+            Sort::Name => items.sort_by_key(|item| item.name.to_lowercase()),
+        }
+        items
+    }
+}
+
+impl FromQuery for AwesomeQuery {
+    fn from_query(query: &str) -> Self {
+        let mut parsed = Self::default();
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match &*key {
+                "q" => parsed.search = value.into_owned(),
+                "category" => parsed.category = Category::from_slug(&value),
+                "type" => parsed.r#type = AwesomeType::from_slug(&value),
+                "sort" => parsed.sort = Sort::from_slug(&value).unwrap_or_default(),
+                "source" => parsed.local = value == "local",
+                _ => {}
+            }
+        }
+        parsed
+    }
+}
+
+impl Display for AwesomeQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if !self.search.is_empty() {
+            query.append_pair("q", &self.search);
+        }
+        if let Some(category) = self.category {
+            query.append_pair("category", category.slug());
+        }
+        if let Some(r#type) = &self.r#type {
+            query.append_pair("type", r#type.slug());
+        }
+        if self.sort != Sort::default() {
+            query.append_pair("sort", self.sort.slug());
+        }
+        if self.local {
+            query.append_pair("source", "local");
+        }
+        write!(f, "{}", query.finish())
+    }
+}
+
+#[test]
+fn query_round_trips() {
+    let queries = [
+        AwesomeQuery::default(),
+        AwesomeQuery {
+            search: "router & co = 100%".to_string(),
+            category: Some(Category::Renderer),
+            r#type: Some(AwesomeType::MadeWith),
+            sort: Sort::Stars,
+            local: true,
+        },
+        AwesomeQuery {
+            category: Some(Category::Util),
+            sort: Sort::Name,
+            ..Default::default()
+        },
+    ];
+    for query in queries {
+        assert_eq!(AwesomeQuery::from_query(&query.to_string()), query);
+    }
+    assert_eq!(AwesomeQuery::default().to_string(), "");
+}
@@ -0,0 +1,204 @@
+//! The awesome list as it was when the site was prebuilt.
+//!
+//! `cargo run --features prebuild -- vendor-awesome` fetches `awesome.json` and the star count of
+//! every GitHub repo in it, and writes them to `awesome_snapshot.json`. The snapshot is compiled into the site, so the page renders (and
+//! prerenders) without waiting on GitHub. When GitHub can't be reached, the list is read from the
+//! file in `AWESOME_JSON` if it is set, and star counts that can't be fetched are kept from the
+//! previous snapshot.
+//!
+//! The README of every GitHub entry is saved to `awesome_readmes/` for the entry's page, and the
+//! platforms it mentions are added to entries that don't list their own. Both are compiled into the
+//! site by `build/awesome.rs`, so the snapshot has to be vendored before the site is built.
+
+use super::Item;
+
+pub(crate) const SNAPSHOT_PATH: &str = "awesome_snapshot.json";
+/// `build/awesome.rs` renders the READMEs in here
+#[cfg(feature = "prebuild")]
+const README_DIR: &str = "awesome_readmes";
+
+/// The platforms prebuild looks for in READMEs, and the words that give them away
+#[cfg(feature = "prebuild")]
+const PLATFORMS: &[(&str, &[&str])] = &[
+    ("Web", &["web", "wasm", "browser"]),
+    ("Desktop", &["desktop"]),
+    ("Mobile", &["mobile", "android", "ios"]),
+    ("LiveView", &["liveview"]),
+    ("Fullstack", &["fullstack", "ssr"]),
+    ("TUI", &["tui", "terminal"]),
+];
+
+#[derive(Default, Clone, serde::Serialize, serde::Deserialize)]
+pub(crate) struct Snapshot {
+    /// When the snapshot was taken, in milliseconds since the epoch
+    pub(crate) generated: i64,
+    pub(crate) items: Vec<Item>,
+}
+
+/// The snapshot the site was built with. Prebuild renders with the one it just wrote instead.
+pub(crate) static SNAPSHOT: once_cell::sync::Lazy<Snapshot> = once_cell::sync::Lazy::new(|| {
+    #[cfg(feature = "prebuild")]
+    if let Some(snapshot) = read_snapshot() {
+        return snapshot;
+    }
+    serde_json::from_str(include_str!("../../../awesome_snapshot.json")).unwrap_or_default()
+});
+
+impl Snapshot {
+    /// The stars the snapshot has for a repo
+    pub(crate) fn stars(&self, username: &str, repo: &str) -> Option<u64> {
+        self.items
+            .iter()
+            .filter_map(|item| Some((item.github.as_ref()?, item.stars?)))
+            .find(|(github, _)| github.username == username && github.repo == repo)
+            .map(|(_, stars)| stars)
+    }
+}
+
+#[cfg(feature = "prebuild")]
+fn read_snapshot() -> Option<Snapshot> {
+    let json = std::fs::read_to_string(SNAPSHOT_PATH).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Fetch the list and its stars and rewrite the snapshot
+#[cfg(feature = "prebuild")]
+pub(crate) async fn vendor() -> std::io::Result<()> {
+    let previous = read_snapshot().unwrap_or_default();
+    let client = reqwest::Client::new();
+
+    let mut items = match fetch_items(&client).await {
+        Ok(items) => items,
+        Err(err) => {
+            println!("couldn't load the awesome list, keeping the snapshot: {err}");
+            previous.items.clone()
+        }
+    };
+
+    std::fs::create_dir_all(README_DIR)?;
+    let mut fetched = 0;
+    let mut readmes = std::collections::HashSet::new();
+    for item in &mut items {
+        let Some(github) = item.github.clone() else {
+            continue;
+        };
+        match fetch_stars(&client, &github.username, &github.repo).await {
+            Ok(stars) => {
+                item.stars = Some(stars);
+                fetched += 1;
+            }
+            Err(_) => item.stars = previous.stars(&github.username, &github.repo),
+        }
+
+        // A README that can't be fetched is kept from the last time it could
+        let path = std::path::Path::new(README_DIR).join(format!("{}.md", item.slug()));
+        if let Ok(readme) = fetch_readme(&client, &github.username, &github.repo).await {
+            std::fs::write(&path, readme)?;
+        }
+        if let Ok(readme) = std::fs::read_to_string(&path) {
+            if item.platforms.is_empty() {
+                item.platforms = detect_platforms(&format!("{}\n{readme}", item.description));
+            }
+            readmes.insert(path);
+        }
+    }
+    println!("fetched the stars of {fetched} awesome repos");
+
+    // Remove the READMEs of entries that left the list
+    for entry in std::fs::read_dir(README_DIR)? {
+        let path = entry?.path();
+        if !readmes.contains(&path) {
+            std::fs::remove_file(path)?;
+        }
+    }
+
+    let snapshot = Snapshot {
+        generated: chrono::Utc::now().timestamp_millis(),
+        items,
+    };
+    let json = serde_json::to_string_pretty(&snapshot)?;
+    std::fs::write(SNAPSHOT_PATH, json + "\n")
+}
+
+#[cfg(feature = "prebuild")]
+async fn fetch_items(client: &reqwest::Client) -> Result<Vec<Item>, String> {
+    let json = match std::env::var_os("AWESOME_JSON") {
+        Some(path) => std::fs::read_to_string(&path).map_err(|err| err.to_string())?,
+        None => client
+            .get(super::ITEM_LIST_LINK)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| err.to_string())?
+            .text()
+            .await
+            .map_err(|err| err.to_string())?,
+    };
+    let (items, errors) = super::validate::parse_items(&json)?;
+    for error in errors {
+        println!("skipping a broken awesome entry: {error}");
+    }
+    Ok(items)
+}
+
+#[cfg(feature = "prebuild")]
+async fn fetch_stars(client: &reqwest::Client, username: &str, repo: &str) -> Result<u64, String> {
+    let response = github_request(client, &format!("repos/{username}/{repo}"))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| err.to_string())?;
+    let stars: super::StarsResponse = response.json().await.map_err(|err| err.to_string())?;
+    Ok(stars.stargazers_count)
+}
+
+/// The README of a repo as markdown, whatever its file is called
+#[cfg(feature = "prebuild")]
+async fn fetch_readme(
+    client: &reqwest::Client,
+    username: &str,
+    repo: &str,
+) -> Result<String, String> {
+    github_request(client, &format!("repos/{username}/{repo}/readme"))
+        .header("Accept", "application/vnd.github.raw")
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|err| err.to_string())?
+        .text()
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// The GitHub API only allows 60 requests an hour without a token, so CI passes `GITHUB_TOKEN`
+#[cfg(feature = "prebuild")]
+fn github_request(client: &reqwest::Client, path: &str) -> reqwest::RequestBuilder {
+    let mut request = client
+        .get(format!("https://api.github.com/{path}"))
+        .header("User-Agent", "dioxuslabs.com");
+    if let Ok(token) = std::env::var("GITHUB_TOKEN") {
+        request = request.bearer_auth(token);
+    }
+    request
+}
+
+/// The platforms a README mentions by name
+#[cfg(feature = "prebuild")]
+fn detect_platforms(text: &str) -> Vec<String> {
+    let words: std::collections::HashSet<String> = text
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|word| word.to_ascii_lowercase())
+        .collect();
+    PLATFORMS
+        .iter()
+        .filter(|(_, keywords)| keywords.iter().any(|keyword| words.contains(*keyword)))
+        .map(|(platform, _)| platform.to_string())
+        .collect()
+}
+
+#[cfg(feature = "prebuild")]
+#[test]
+fn detects_platforms_by_word() {
+    let readme = "# dioxus-thing\n\nRuns on Desktop and in the browser (WASM). Webview not needed.";
+    assert_eq!(detect_platforms(readme), ["Web", "Desktop"]);
+}
@@ -0,0 +1,439 @@
+//! A proxy for the star counts of the awesome repos.
+//!
+//! Asking GitHub from every reader's browser runs into the rate limit of the API after a few
+//! visits. When the site is served with the `server` feature, the awesome page sends all of its
+//! repos to [`fetch_stars`] at once instead. The server only looks up repos that are on the awesome
+//! list, so nobody else can spend its rate limit. It asks GitHub (with `GITHUB_TOKEN` if it is set)
+//! only for the repos it hasn't looked up in the last [`store::TTL`], and keeps the counts, and the
+//! repos GitHub doesn't know, in a file that every reader shares, `star_cache.json` unless
+//! `STAR_CACHE_PATH` is set.
+//!
+//! `GITHUB_API_URL` points the proxy at another API, like the mock GitHub the tests run against.
+
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// The most repos a single call may look up
+pub(crate) const MAX_BATCH: usize = 100;
+
+/// The stars of each `(username, repo)`, in the same order. Repos GitHub doesn't know, and repos
+/// that aren't on the awesome list, are `None`.
+#[server]
+pub(crate) async fn fetch_stars(
+    repos: Vec<(String, String)>,
+) -> Result<Vec<Option<u64>>, ServerFnError> {
+    if repos.len() > MAX_BATCH {
+        return Err(ServerFnError::ServerError(format!(
+            "can't look up more than {MAX_BATCH} repos at once"
+        )));
+    }
+    let known = store::known_repos().await;
+    Ok(store::StarCache::from_env().stars(&repos, &known).await?)
+}
+
+/// Ask the proxy for the stars of every repo, in batches it accepts. Batches that fail are left
+/// out, like when the site is served without a server.
+pub(crate) async fn fetch_all(repos: Vec<(String, String)>) -> HashMap<(String, String), u64> {
+    let mut stars = HashMap::new();
+    for batch in repos.chunks(MAX_BATCH) {
+        if let Ok(counts) = fetch_stars(batch.to_vec()).await {
+            for (repo, count) in batch.iter().zip(counts) {
+                if let Some(count) = count {
+                    stars.insert(repo.clone(), count);
+                }
+            }
+        }
+    }
+    stars
+}
+
+#[cfg(feature = "server")]
+pub(crate) mod store {
+    use std::collections::{HashMap, HashSet};
+    use std::path::PathBuf;
+    use std::time::{Duration, Instant};
+
+    /// How long a star count is served before GitHub is asked again
+    pub(crate) const TTL: Duration = Duration::from_secs(6 * 60 * 60);
+
+    /// Guards the cache file. GitHub is asked outside of it, so a slow lookup doesn't hold up the
+    /// readers whose counts are cached.
+    static LOCK: once_cell::sync::Lazy<tokio::sync::Mutex<()>> =
+        once_cell::sync::Lazy::new(|| tokio::sync::Mutex::new(()));
+
+    /// The repos some lookup is fetching right now. A burst of readers asks GitHub for each repo
+    /// only once, the others serve what the cache has until the fetch is saved.
+    static IN_FLIGHT: once_cell::sync::Lazy<std::sync::Mutex<HashSet<String>>> =
+        once_cell::sync::Lazy::new(Default::default);
+
+    /// The repos on the published list and when they were fetched
+    static LIST: once_cell::sync::Lazy<tokio::sync::Mutex<Option<(Instant, HashSet<String>)>>> =
+        once_cell::sync::Lazy::new(Default::default);
+
+    #[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+    struct Entry {
+        /// `None` if GitHub doesn't know the repo
+        stars: Option<u64>,
+        /// When the stars were fetched, in seconds since the epoch
+        fetched: i64,
+    }
+
+    pub(crate) struct StarCache {
+        pub(crate) path: PathBuf,
+        pub(crate) ttl: Duration,
+        pub(crate) api: String,
+        pub(crate) token: Option<String>,
+    }
+
+    /// The `username/repo` of every repo on the awesome list: the ones in the snapshot the site was
+    /// built with and the ones on the published list, which is fetched again after the [`TTL`].
+    pub(crate) async fn known_repos() -> HashSet<String> {
+        let mut list = LIST.lock().await;
+        if list
+            .as_ref()
+            .map_or(true, |(fetched, _)| fetched.elapsed() >= TTL)
+        {
+            // If the list can't be fetched, the last one is kept until the next try
+            let repos = match fetch_list().await {
+                Ok(repos) => repos,
+                Err(_) => list.take().map(|(_, repos)| repos).unwrap_or_default(),
+            };
+            *list = Some((Instant::now(), repos));
+        }
+
+        let mut known = list
+            .as_ref()
+            .map(|(_, repos)| repos.clone())
+            .unwrap_or_default();
+        known.extend(repo_keys(&super::super::SNAPSHOT.items));
+        known
+    }
+
+    async fn fetch_list() -> Result<HashSet<String>, String> {
+        let json = reqwest::get(super::super::ITEM_LIST_LINK)
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| err.to_string())?
+            .text()
+            .await
+            .map_err(|err| err.to_string())?;
+        let (items, _) = super::super::validate::parse_items(&json)?;
+        Ok(repo_keys(&items))
+    }
+
+    fn repo_keys(items: &[super::super::Item]) -> HashSet<String> {
+        super::super::github_repos(items)
+            .into_iter()
+            .map(|(username, repo)| format!("{username}/{repo}"))
+            .collect()
+    }
+
+    /// Whether a username or repo is a plain GitHub name, so it can't point the request at
+    /// another path of the API
+    pub(crate) fn valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name != "."
+            && !name.contains("..")
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    }
+
+    /// Takes the repos out of [`IN_FLIGHT`] once they are fetched, or if the lookup is dropped
+    struct InFlight(Vec<String>);
+
+    impl InFlight {
+        /// Claim the repos no other lookup is fetching
+        fn claim(keys: Vec<String>) -> Self {
+            let mut in_flight = IN_FLIGHT.lock().unwrap();
+            Self(
+                keys.into_iter()
+                    .filter(|key| in_flight.insert(key.clone()))
+                    .collect(),
+            )
+        }
+    }
+
+    impl Drop for InFlight {
+        fn drop(&mut self) {
+            let mut in_flight = IN_FLIGHT.lock().unwrap();
+            for key in &self.0 {
+                in_flight.remove(key);
+            }
+        }
+    }
+
+    impl StarCache {
+        pub(crate) fn from_env() -> Self {
+            Self {
+                path: std::env::var_os("STAR_CACHE_PATH")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| PathBuf::from("star_cache.json")),
+                ttl: TTL,
+                api: std::env::var("GITHUB_API_URL")
+                    .unwrap_or_else(|_| "https://api.github.com".to_string()),
+                token: std::env::var("GITHUB_TOKEN").ok(),
+            }
+        }
+
+        /// Look up the stars of every repo in `known`, fetching the ones that aren't cached or are
+        /// too old. When GitHub can't be reached, an old count is better than none.
+        pub(crate) async fn stars(
+            &self,
+            repos: &[(String, String)],
+            known: &HashSet<String>,
+        ) -> std::io::Result<Vec<Option<u64>>> {
+            let keys: Vec<Option<String>> = repos
+                .iter()
+                .map(|(username, repo)| {
+                    let key = format!("{username}/{repo}");
+                    (valid_name(username) && valid_name(repo) && known.contains(&key))
+                        .then_some(key)
+                })
+                .collect();
+
+            let now = chrono::Utc::now().timestamp();
+            let stale = {
+                let _lock = LOCK.lock().await;
+                let cache = self.load()?;
+                let mut stale: Vec<String> = keys
+                    .iter()
+                    .flatten()
+                    .filter(|key| {
+                        cache.get(*key).map_or(true, |entry| {
+                            now - entry.fetched >= self.ttl.as_secs() as i64
+                        })
+                    })
+                    .cloned()
+                    .collect();
+                stale.sort();
+                stale.dedup();
+                stale
+            };
+
+            let claimed = InFlight::claim(stale);
+            let mut fetched = Vec::new();
+            if !claimed.0.is_empty() {
+                let client = reqwest::Client::new();
+                let results =
+                    futures::future::join_all(claimed.0.iter().map(|key| self.fetch(&client, key)))
+                        .await;
+                for (key, stars) in claimed.0.iter().zip(results) {
+                    if let Ok(stars) = stars {
+                        fetched.push((
+                            key.clone(),
+                            Entry {
+                                stars,
+                                fetched: now,
+                            },
+                        ));
+                    }
+                }
+            }
+
+            let _lock = LOCK.lock().await;
+            let mut cache = self.load()?;
+            if !fetched.is_empty() {
+                cache.extend(fetched);
+                self.save(&cache)?;
+            }
+            drop(claimed);
+
+            Ok(keys
+                .iter()
+                .map(|key| cache.get(key.as_ref()?)?.stars)
+                .collect())
+        }
+
+        /// The stars of a repo, or `None` if GitHub doesn't know it
+        async fn fetch(
+            &self,
+            client: &reqwest::Client,
+            key: &str,
+        ) -> Result<Option<u64>, reqwest::Error> {
+            let mut request = client
+                .get(format!("{}/repos/{key}", self.api))
+                .header("User-Agent", "dioxuslabs.com");
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+            let response = request.send().await?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            let stars: super::super::StarsResponse = response.error_for_status()?.json().await?;
+            Ok(Some(stars.stargazers_count))
+        }
+
+        /// A missing or broken cache is an empty one
+        fn load(&self) -> std::io::Result<HashMap<String, Entry>> {
+            match std::fs::read_to_string(&self.path) {
+                Ok(json) => Ok(serde_json::from_str(&json).unwrap_or_default()),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(HashMap::new()),
+                Err(err) => Err(err),
+            }
+        }
+
+        fn save(&self, cache: &HashMap<String, Entry>) -> std::io::Result<()> {
+            std::fs::write(&self.path, serde_json::to_string(cache)?)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use super::store::{valid_name, StarCache};
+    use axum::extract::{Path, State};
+    use axum::http::{HeaderMap, StatusCode};
+    use std::collections::HashSet;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// The requests the mock GitHub got: the repo and the authorization header
+    type Requests = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    /// Answer `/repos/{username}/{repo}` like GitHub does for the repos in `stars`, and 404 for the
+    /// rest
+    async fn mock_github(stars: &'static [(&'static str, u64)]) -> (String, Requests) {
+        let requests = Requests::default();
+        let app = axum::Router::new()
+            .route(
+                "/repos/:username/:repo",
+                axum::routing::get(
+                    |State(requests): State<Requests>,
+                     Path((username, repo)): Path<(String, String)>,
+                     headers: HeaderMap| async move {
+                        let key = format!("{username}/{repo}");
+                        let auth = headers
+                            .get("authorization")
+                            .and_then(|value| value.to_str().ok())
+                            .map(str::to_string);
+                        requests.lock().unwrap().push((key.clone(), auth));
+                        match stars.iter().find(|(repo, _)| *repo == key) {
+                            Some((_, stars)) => {
+                                Ok(axum::Json(serde_json::json!({ "stargazers_count": stars })))
+                            }
+                            None => Err(StatusCode::NOT_FOUND),
+                        }
+                    },
+                ),
+            )
+            .with_state(requests.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let api = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (api, requests)
+    }
+
+    fn repo(key: &str) -> (String, String) {
+        let (username, repo) = key.split_once('/').unwrap();
+        (username.to_string(), repo.to_string())
+    }
+
+    #[tokio::test]
+    async fn caches_stars_until_they_expire() {
+        let (api, requests) = mock_github(&[("a/one", 10), ("b/two", 20)]).await;
+        let path = std::env::temp_dir().join(format!("star_cache_{}.json", std::process::id()));
+        _ = std::fs::remove_file(&path);
+        let mut cache = StarCache {
+            path: path.clone(),
+            ttl: Duration::from_secs(60 * 60),
+            api,
+            token: Some("secret".to_string()),
+        };
+        let known: HashSet<String> = ["a/one", "b/two", "c/missing"]
+            .iter()
+            .map(|key| key.to_string())
+            .collect();
+        let repos = [
+            repo("a/one"),
+            repo("b/two"),
+            repo("a/one"),
+            repo("c/missing"),
+        ];
+
+        // Every repo is fetched once, with the token
+        assert_eq!(
+            cache.stars(&repos, &known).await.unwrap(),
+            [Some(10), Some(20), Some(10), None]
+        );
+        let mut fetched = requests.lock().unwrap().clone();
+        fetched.sort();
+        let auth = Some("Bearer secret".to_string());
+        assert_eq!(
+            fetched,
+            [
+                ("a/one".to_string(), auth.clone()),
+                ("b/two".to_string(), auth.clone()),
+                ("c/missing".to_string(), auth.clone()),
+            ]
+        );
+
+        // Counts that are still fresh come from the cache, and so do repos GitHub doesn't know
+        requests.lock().unwrap().clear();
+        assert_eq!(
+            cache.stars(&repos, &known).await.unwrap(),
+            [Some(10), Some(20), Some(10), None]
+        );
+        assert!(requests.lock().unwrap().is_empty());
+
+        // Once they expire they are fetched again
+        cache.ttl = Duration::ZERO;
+        assert_eq!(cache.stars(&repos[..1], &known).await.unwrap(), [Some(10)]);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn only_looks_up_listed_repos() {
+        let (api, requests) = mock_github(&[("d/listed", 10)]).await;
+        let path =
+            std::env::temp_dir().join(format!("star_cache_listed_{}.json", std::process::id()));
+        _ = std::fs::remove_file(&path);
+        let cache = StarCache {
+            path: path.clone(),
+            ttl: Duration::from_secs(60 * 60),
+            api,
+            token: None,
+        };
+        let known: HashSet<String> = ["d/listed", "d/..", "d/listed?x=1"]
+            .iter()
+            .map(|key| key.to_string())
+            .collect();
+        let repos = [
+            repo("d/listed"),
+            repo("d/unlisted"),
+            repo("d/.."),
+            repo("d/listed?x=1"),
+            ("d".to_string(), "listed#x".to_string()),
+            ("..".to_string(), "listed".to_string()),
+        ];
+
+        assert_eq!(
+            cache.stars(&repos, &known).await.unwrap(),
+            [Some(10), None, None, None, None, None]
+        );
+        let fetched: Vec<_> = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.clone())
+            .collect();
+        assert_eq!(fetched, ["d/listed"]);
+
+        _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn accepts_only_github_names() {
+        for name in ["dioxus", "awesome-dioxus", "dioxus_std", "blitz.rs", "A1"] {
+            assert!(valid_name(name), "{}", name);
+        }
+        for name in [
+            "", ".", "..", "a..b", "a/b", "a?b", "a#b", "a b", "a%2F", "ä",
+        ] {
+            assert!(!valid_name(name), "{}", name);
+        }
+    }
+}
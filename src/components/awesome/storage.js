@@ -1,28 +1,8 @@
-// Handle caching of github stars
-
-// Two days
+// How long the star counts of the awesome snapshot are trusted: two days
 const STAR_EXPIRE_TIME = 172800000;
 
-export function get_stars(name) {
-  let item = localStorage.getItem(name);
-  let data = JSON.parse(item);
-
-  if (!data) {
-    return null;
-  }
-
-  if (data.expires <= Date.now()) {
-    localStorage.removeItem(name);
-    return null;
-  }
-
-  return data.stars;
+// Whether something from the given time (in milliseconds since the epoch) is recent enough to
+// not fetch again
+export function is_fresh(time) {
+  return Date.now() - time < STAR_EXPIRE_TIME;
 }
-
-export function set_stars(name, value) {
-  let expires = Date.now() + STAR_EXPIRE_TIME;
-  let data = { stars: value, expires };
-
-  let converted = JSON.stringify(data);
-  localStorage.setItem(name, converted);
-}
\ No newline at end of file
@@ -0,0 +1,143 @@
+//! Checks `awesome.json` one entry at a time, so a single bad entry is reported (and skipped) instead
+//! of breaking the whole list.
+//!
+//! `cargo run --features prebuild -- validate-awesome path/to/awesome.json` prints the problems with
+//! every entry. The awesome page can also preview a local file with `/awesome?source=local`.
+
+use super::Item;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// A problem with one entry of the list
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct EntryError {
+    /// The position of the entry in the list, starting at 0
+    pub(crate) index: usize,
+    pub(crate) name: Option<String>,
+    pub(crate) message: String,
+}
+
+impl Display for EntryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "entry {} ({name}): {}", self.index, self.message),
+            None => write!(f, "entry {}: {}", self.index, self.message),
+        }
+    }
+}
+
+/// Parse the list, keeping every entry that can be shown. Only fails if the file isn't a json array.
+pub(crate) fn parse_items(json: &str) -> Result<(Vec<Item>, Vec<EntryError>), String> {
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|err| format!("not a list of entries: {err}"))?;
+
+    let mut items = Vec::new();
+    let mut errors = Vec::new();
+    let mut names = HashSet::new();
+    let mut slugs = HashSet::new();
+    for (index, entry) in entries.into_iter().enumerate() {
+        let name = entry
+            .get("name")
+            .and_then(|name| name.as_str())
+            .map(str::to_string);
+        let error = |message: String| EntryError {
+            index,
+            name: name.clone(),
+            message,
+        };
+
+        let item = match serde_json::from_value::<Item>(entry) {
+            Ok(item) => item,
+            Err(err) => {
+                errors.push(error(err.to_string()));
+                continue;
+            }
+        };
+        // The name is the key of the card, two cards with the same key would be mixed up
+        if !names.insert(item.name.clone()) {
+            errors.push(error("another entry has the same name".to_string()));
+            continue;
+        }
+        errors.extend(problems(&item, &slugs).into_iter().map(error));
+        slugs.insert(item.slug());
+        items.push(item);
+    }
+
+    Ok((items, errors))
+}
+
+/// Problems with an entry that parses, but wouldn't show up right. `slugs` are the slugs of the
+/// entries before it, the first entry with a slug gets the page.
+fn problems(item: &Item, slugs: &HashSet<String>) -> Vec<String> {
+    let mut problems = Vec::new();
+    let slug = item.slug();
+    if item.name.trim().is_empty() {
+        problems.push("the name is empty".to_string());
+    } else if slug.is_empty() {
+        problems.push("the name needs a letter or digit for the url of its page".to_string());
+    } else if slugs.contains(&slug) {
+        problems.push(format!(
+            "the page /awesome/{slug} already belongs to another entry, pick a more distinct name"
+        ));
+    }
+    if item.description.trim().is_empty() {
+        problems.push("the description is empty".to_string());
+    }
+    match (&item.github, &item.link) {
+        (None, None) => problems.push("needs either `github` or `link`".to_string()),
+        (Some(github), _) if github.username.trim().is_empty() || github.repo.trim().is_empty() => {
+            problems.push("`github` needs both a `username` and a `repo`".to_string())
+        }
+        _ => {}
+    }
+    problems
+}
+
+/// Validate a file and print every problem. Returns false if there were any.
+#[cfg(feature = "prebuild")]
+pub(crate) fn validate_file(path: &std::path::Path) -> bool {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(err) => {
+            println!("couldn't read {}: {err}", path.display());
+            return false;
+        }
+    };
+    match parse_items(&json) {
+        Ok((items, errors)) if errors.is_empty() => {
+            println!("{}: all {} entries are valid", path.display(), items.len());
+            true
+        }
+        Ok((_, errors)) => {
+            println!("{}: found {} problems", path.display(), errors.len());
+            for error in errors {
+                println!("  {error}");
+            }
+            false
+        }
+        Err(err) => {
+            println!("{}: {err}", path.display());
+            false
+        }
+    }
+}
+
+#[test]
+fn reports_each_bad_entry() {
+    let json = r#"[
+        { "name": "Good", "description": "Works", "type": "Awesome", "category": "Util", "link": "https://example.com" },
+        { "name": "Unknown category", "description": "Oops", "type": "Awesome", "category": "Icons", "link": "https://example.com" },
+        { "description": "No name", "type": "MadeWith", "category": "App" },
+        { "name": "Good", "description": "Again", "type": "Awesome", "category": "Misc", "github": { "username": "a", "repo": "b" } },
+        { "name": "good!", "description": "Same page", "type": "Awesome", "category": "Misc", "link": "https://example.com" }
+    ]"#;
+    let (items, errors) = parse_items(json).unwrap();
+
+    assert_eq!(items.len(), 2);
+    let indices: Vec<_> = errors.iter().map(|error| error.index).collect();
+    assert_eq!(indices, [1, 2, 3, 4]);
+    assert!(errors[0].message.contains("Icons"));
+    assert_eq!(errors[1].name, None);
+    assert_eq!(errors[2].message, "another entry has the same name");
+    assert!(errors[3].message.contains("/awesome/good"));
+}
@@ -2,126 +2,29 @@ use crate::icons;
 use crate::Link;
 use dioxus::prelude::*;
 use dioxus_router::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub(crate) struct BlogPost {
-    category: &'static str,
-    date: &'static str,
-    title: &'static str,
-    description: &'static str,
-    link: &'static str,
-    content: &'static str,
+    pub(crate) category: &'static str,
+    pub(crate) date: &'static str,
+    /// The `date` as `(year, month, day)`, checked by `build/posts.rs`
+    pub(crate) published: (i32, u32, u32),
+    pub(crate) title: &'static str,
+    pub(crate) description: &'static str,
+    pub(crate) link: &'static str,
+    /// The path of the file the content is included from, relative to the root of the repo
+    pub(crate) source: &'static str,
+    pub(crate) content: &'static str,
 }
 
-pub(crate) const POST_RELEASE_050: BlogPost = BlogPost {
-    category: "Release Notes",
-    date: "March 21, 2024",
-    title: "Announcing Dioxus 0.5",
-    description: "A signal rewrite, zero unsafe, no lifetimes, unified launch, and more! ",
-    link: "/blog/release-050/",
-    content: include_str!("../../../posts/release050.html"),
-};
+/// The path of the Atom feed, generated during prebuild
+pub(crate) const ATOM_FEED: &str = "/blog/feed.xml";
+/// The path of the RSS feed, generated during prebuild
+pub(crate) const RSS_FEED: &str = "/blog/rss.xml";
 
-#[component]
-pub(crate) fn PostRelease050() -> Element {
-    rsx! { SinglePost { post: POST_RELEASE_050 } }
-}
-
-pub(crate) const POST_TEMPLATE: BlogPost = BlogPost {
-    category: "Tech",
-    date: "Dec 11, 2022",
-    title: "Making Dioxus (almost) as fast as SolidJS",
-    description:
-        "Using a new technique called subtree memoization, Dioxus is now almost as fast as SolidJS.",
-    link: "/blog/templates-diffing/",
-    content: include_str!("../../../posts/templates.html"),
-};
-
-#[component]
-pub(crate) fn PostTemplate() -> Element {
-    rsx! { SinglePost { post: POST_TEMPLATE } }
-}
-
-pub(crate) const POST_FULLTINME: BlogPost = BlogPost {
-    category: "Misc",
-    date: "May 5 2023",
-    title: "Going full time on Dioxus",
-    description:
-        "Dioxus is now my full time job! I'm so excited to be able to work on this full time.",
-    link: "/blog/going-fulltime/",
-    content: include_str!("../../../posts/fulltime.html"),
-};
-
-#[component]
-pub(crate) fn PostFulltime() -> Element {
-    rsx! { SinglePost { post: POST_FULLTINME } }
-}
-
-pub(crate) const POST_RELEASE_040: BlogPost = BlogPost {
-    category: "Release Notes",
-    date: "Aug 1 2023",
-    title: "Announcing Dioxus 0.4",
-    description: "An overhauled router, fullstack, desktop hotreloading, and more!",
-    link: "/blog/release-040/",
-    content: include_str!("../../../posts/release040.html"),
-};
-
-#[component]
-pub(crate) fn PostRelease040() -> Element {
-    rsx! { SinglePost { post: POST_RELEASE_040 } }
-}
-
-pub(crate) const POST_RELEASE_030: BlogPost = BlogPost {
-    category: "Release Notes",
-    date: "Feb 8 2023",
-    title: "Announcing Dioxus 0.3",
-    description: "The next big release of Dioxus is here! Templates, autoformatting, multiwindow support, and more!",
-    link: "/blog/release-030/",
-    content: include_str!("../../../posts/release030.html"),
-};
-
-#[component]
-pub(crate) fn PostRelease030() -> Element {
-    rsx! { SinglePost { post: POST_RELEASE_030 } }
-}
-
-pub(crate) const POST_RELEASE_020: BlogPost = BlogPost {
-    category: "Release Notes",
-    date: "Mar 9 2022",
-    title: "Announcing Dioxus 0.2",
-    description: "Just over two months in, and we already have a ton of awesome changes to Dioxus!",
-    link: "/blog/release-020/",
-    content: include_str!("../../../posts/release020.html"),
-};
-
-#[component]
-pub(crate) fn PostRelease020() -> Element {
-    rsx! { SinglePost { post: POST_RELEASE_020 } }
-}
-
-pub(crate) const POST_RELEASE_010: BlogPost = BlogPost {
-    category: "Release Notes",
-    date: "Jan 3 2022",
-    title: "Announcing Dioxus 0.1",
-    description: "After months of work, we're very excited to release the first version of Dioxus! Dioxus is a new library for building interactive user interfaces with Rust. It is built around a VirtualDOM, making it portable for the web, desktop, server, mobile, and more.",
-    link: "/blog/introducing-dioxus/",
-    content: include_str!("../../../posts/release.html"),
-};
-
-#[component]
-pub(crate) fn PostRelease010() -> Element {
-    rsx! { SinglePost { post: POST_RELEASE_010 } }
-}
-
-pub(crate) const POSTS: &[BlogPost] = &[
-    POST_RELEASE_050,
-    POST_RELEASE_040,
-    POST_FULLTINME,
-    POST_RELEASE_030,
-    POST_TEMPLATE,
-    POST_RELEASE_020,
-    POST_RELEASE_010,
-];
+// The posts, their routes and components are generated from the markdown in `posts/` by `build.rs`
+include!(concat!(env!("OUT_DIR"), "/posts.rs"));
 
 #[component]
 pub(crate) fn BlogList() -> Element {
@@ -154,12 +57,10 @@ pub(crate) fn SinglePost(post: BlogPost) -> Element {
 
     rsx! {
         section { class: "text-gray-600 body-font dark:bg-ideblack max-w-screen-md mx-auto pt-24 font-light",
-            script { "Prism.highlightAll()" }
             article {
                 class: "markdown-body px-2  dioxus-blog-post",
                 dangerous_inner_html: format_args!("{}", content)
             }
-            script { "Prism.highlightAll()" }
         }
     }
 }
@@ -222,3 +123,16 @@ fn BlogPostItem(post: &'static BlogPost) -> Element {
         }
     }
 }
+
+#[test]
+fn post_dates_are_valid() {
+    for post in POSTS {
+        let (year, month, day) = post.published;
+        assert!(
+            chrono::NaiveDate::from_ymd_opt(year, month, day).is_some(),
+            "invalid date {:?} for post {:?}",
+            post.date,
+            post.title
+        );
+    }
+}
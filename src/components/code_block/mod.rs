@@ -0,0 +1,204 @@
+//! Copy buttons, filenames, highlighted lines and tabs for the code blocks of the docs.
+//!
+//! `build.rs` renders every code block of the docs into a [`CodeBlock`] with its full info string and
+//! its lines already highlighted by syntect. Consecutive blocks of the same group are rendered into
+//! one [`CodeGroup`] instead. The info string takes comma separated attributes after the language:
+//!
+//! - `filename=main.rs` shows the name of the file above the code
+//! - `hl_lines=3-5 8` highlights lines 3 to 5 and 8
+//! - `group=setup` shows consecutive blocks of the same group as tabs, named after their filename
+//!
+//! Rust blocks that define an `App` component also get a button to run and edit them in the
+//! [`playground`] unless they are marked `ignore`.
+
+use crate::*;
+
+mod playground;
+pub(crate) use playground::*;
+
+/// The attributes of a code block, parsed from its info string
+#[derive(Default, PartialEq, Eq, Debug)]
+pub(crate) struct FenceInfo {
+    pub(crate) language: String,
+    pub(crate) filename: Option<String>,
+    /// Inclusive ranges of line numbers, starting at 1
+    pub(crate) highlight: Vec<(usize, usize)>,
+    pub(crate) group: Option<String>,
+    /// Whether the block can be opened in the playground, if it defines an `App` component
+    pub(crate) runnable: bool,
+}
+
+impl FenceInfo {
+    pub(crate) fn parse(info: &str) -> Self {
+        let mut parts = info.split(',').map(str::trim);
+        let language = parts.next().unwrap_or_default();
+        let mut fence = FenceInfo {
+            language: language.to_string(),
+            runnable: matches!(language, "rust" | "rs"),
+            ..Default::default()
+        };
+
+        for part in parts {
+            let Some((key, value)) = part.split_once('=') else {
+                // `no_run` only means something to rustdoc, the examples run fine in a browser
+                if part == "ignore" {
+                    fence.runnable = false;
+                }
+                continue;
+            };
+            let value = value.trim().trim_matches('"');
+            match key.trim() {
+                "filename" => fence.filename = Some(value.to_string()),
+                "hl_lines" => fence.highlight = parse_line_ranges(value),
+                "group" => fence.group = Some(value.to_string()),
+                "playground" => fence.runnable &= value != "false",
+                _ => {}
+            }
+        }
+        fence
+    }
+}
+
+/// Parse `3-5 8` into `[(3, 5), (8, 8)]`, skipping anything that isn't a line number
+fn parse_line_ranges(value: &str) -> Vec<(usize, usize)> {
+    value
+        .split_whitespace()
+        .filter_map(|range| match range.split_once('-') {
+            Some((start, end)) => Some((start.parse().ok()?, end.parse().ok()?)),
+            None => range.parse().ok().map(|line| (line, line)),
+        })
+        .filter(|(start, end)| start <= end)
+        .collect()
+}
+
+/// A code block of the docs as `build.rs` renders it
+#[derive(PartialEq)]
+pub(crate) struct HighlightedCode {
+    /// The info string of the block, see [`FenceInfo`]
+    pub(crate) info: &'static str,
+    pub(crate) code: &'static str,
+    /// The html of every line, highlighted by syntect
+    pub(crate) lines: &'static [&'static str],
+}
+
+/// The background of the syntect theme the blocks are highlighted with
+const CODE_BACKGROUND: &str = "#2b303b";
+
+#[component]
+pub(crate) fn CodeBlock(block: &'static HighlightedCode) -> Element {
+    let fence = FenceInfo::parse(block.info);
+
+    rsx! {
+        div { class: "code-block relative my-4 rounded-md overflow-hidden",
+            if let Some(filename) = &fence.filename {
+                div { class: "bg-ghdarkmetal text-gray-100 text-sm px-4 py-2", "{filename}" }
+            }
+            CodeBody { block }
+        }
+    }
+}
+
+/// Code blocks of the same group, shown as tabs named after their filename
+#[component]
+pub(crate) fn CodeGroup(blocks: &'static [HighlightedCode]) -> Element {
+    let mut selected = use_signal(|| 0);
+    let block = &blocks[selected().min(blocks.len() - 1)];
+
+    rsx! {
+        div { class: "code-group my-4 rounded-md overflow-hidden",
+            ul { class: "flex text-sm leading-6 text-gray-100 bg-ghdarkmetal pt-3 px-3 overflow-auto whitespace-nowrap",
+                for (index , tab) in blocks.iter().enumerate() {
+                    li { class: "flex-none",
+                        button {
+                            class: "relative py-2 px-4 rounded-t-md",
+                            class: if selected() == index { "bg-ghmetal text-white" } else { "bg-ghdarkmetal" },
+                            r#type: "button",
+                            onclick: move |_| selected.set(index),
+                            {tab_name(tab, index)}
+                        }
+                    }
+                }
+            }
+            div { class: "code-block relative", CodeBody { key: "{selected}", block } }
+        }
+    }
+}
+
+fn tab_name(block: &HighlightedCode, index: usize) -> String {
+    let fence = FenceInfo::parse(block.info);
+    match fence.filename {
+        Some(filename) => filename,
+        None if !fence.language.is_empty() => fence.language,
+        None => format!("Tab {}", index + 1),
+    }
+}
+
+/// The highlighted lines of a block with its copy and run buttons
+#[component]
+fn CodeBody(block: &'static HighlightedCode) -> Element {
+    let fence = FenceInfo::parse(block.info);
+    let highlighted =
+        |line: usize| fence.highlight.iter().any(|(start, end)| (*start..=*end).contains(&line));
+    let runnable = fence.runnable && block.code.contains("fn App(");
+    let mut copied = use_signal(|| false);
+
+    rsx! {
+        pre { class: "relative", margin: "0", background_color: CODE_BACKGROUND,
+            code {
+                for (index , line) in block.lines.iter().enumerate() {
+                    div {
+                        class: if highlighted(index + 1) { "bg-white/10 border-l-2 border-sky-400 -ml-4 pl-[14px] -mr-4 pr-4" },
+                        dangerous_inner_html: *line
+                    }
+                }
+            }
+        }
+        button {
+            class: "absolute top-2 right-2 text-xs text-gray-100 bg-ghdarkmetal rounded px-2 py-1 opacity-70 hover:opacity-100",
+            r#type: "button",
+            onclick: move |_| async move {
+                let code = serde_json::to_string(block.code).unwrap();
+                eval(&format!("navigator.clipboard.writeText({code})"));
+                copied.set(true);
+                #[cfg(feature = "web")]
+                gloo_timers::future::TimeoutFuture::new(2000).await;
+                copied.set(false);
+            },
+            if copied() { "Copied!" } else { "Copy" }
+        }
+        if runnable {
+            button {
+                class: "absolute top-2 right-16 text-xs text-gray-100 bg-ghdarkmetal rounded px-2 py-1 opacity-70 hover:opacity-100",
+                r#type: "button",
+                onclick: move |_| open_playground(block.code.to_string()),
+                "Run/Edit"
+            }
+        }
+    }
+}
+
+#[test]
+fn parses_fence_info() {
+    assert_eq!(
+        FenceInfo::parse(r#"rust, filename="main.rs", hl_lines=2-3 5, group=setup"#),
+        FenceInfo {
+            language: "rust".to_string(),
+            filename: Some("main.rs".to_string()),
+            highlight: vec![(2, 3), (5, 5)],
+            group: Some("setup".to_string()),
+            runnable: true,
+        }
+    );
+    assert!(!FenceInfo::parse("rust, ignore").runnable);
+    assert!(!FenceInfo::parse("rust, playground=false").runnable);
+    assert!(FenceInfo::parse("rust, no_run").runnable);
+    assert!(!FenceInfo::parse("toml").runnable);
+    assert_eq!(FenceInfo::parse(""), FenceInfo::default());
+}
+
+#[test]
+fn parses_line_ranges() {
+    assert_eq!(parse_line_ranges("3-5 8"), [(3, 5), (8, 8)]);
+    assert_eq!(parse_line_ranges("5-3 x 1-y 2"), [(2, 2)]);
+    assert!(parse_line_ranges("").is_empty());
+}
@@ -0,0 +1,93 @@
+use crate::docs::DemoFrame;
+use crate::*;
+
+/// The source open in the playground, if it is open
+static PLAYGROUND_SOURCE: GlobalSignal<Option<String>> = Signal::global(|| None);
+
+pub(crate) fn open_playground(source: String) {
+    *PLAYGROUND_SOURCE.write() = Some(source);
+}
+
+/// An editor for the source of an example next to what it renders.
+///
+/// Compiling only works when the site is served with the `playground` feature, the static site doesn't
+/// have a server to build on.
+pub(crate) fn Playground() -> Element {
+    let Some(initial) = PLAYGROUND_SOURCE() else {
+        return None;
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 bg-gray-500 bg-opacity-50 flex items-center justify-center",
+            onclick: move |_| *PLAYGROUND_SOURCE.write() = None,
+            div {
+                class: "bg-white dark:bg-ideblack rounded-2xl p-4 m-4 w-full max-w-screen-xl max-h-[90vh] overflow-y-auto text-gray-800 dark:text-gray-100",
+                onclick: move |evt| evt.stop_propagation(),
+                PlaygroundEditor { key: "{initial}", initial: initial.clone() }
+            }
+        }
+    }
+}
+
+#[component]
+fn PlaygroundEditor(initial: String) -> Element {
+    let mut source = use_signal(|| initial.clone());
+    let mut output = use_signal(|| None::<Result<String, String>>);
+    let mut compiling = use_signal(|| false);
+
+    let run = move |_| async move {
+        compiling.set(true);
+        let result = crate::playground::compile_playground(source())
+            .await
+            .map_err(|err| err.to_string());
+        output.set(Some(result));
+        compiling.set(false);
+    };
+
+    rsx! {
+        div { class: "flex flex-row items-center justify-between pb-4",
+            h2 { class: "text-xl font-semibold", "Playground" }
+            div { class: "flex flex-row gap-2",
+                button {
+                    class: "rounded bg-sky-500 text-white px-4 py-1 disabled:opacity-50",
+                    disabled: compiling(),
+                    onclick: run,
+                    if compiling() { "Compiling..." } else { "Run" }
+                }
+                button {
+                    class: "rounded border border-gray-300 dark:border-gray-700 px-4 py-1",
+                    onclick: move |_| *PLAYGROUND_SOURCE.write() = None,
+                    "Close"
+                }
+            }
+        }
+        div { class: "flex flex-col lg:flex-row gap-4",
+            textarea {
+                class: "font-mono text-sm w-full lg:w-1/2 min-h-[50vh] p-2 rounded bg-ghmetal text-gray-100",
+                spellcheck: false,
+                value: "{source}",
+                oninput: move |evt| source.set(evt.value())
+            }
+            div { class: "w-full lg:w-1/2",
+                match output() {
+                    None => rsx! {
+                        p { class: "text-gray-500", "Press run to compile the example" }
+                    },
+                    Some(Ok(page)) => rsx! {
+                        DemoFrame {
+                            iframe {
+                                class: "w-full min-h-[40vh] border-none",
+                                sandbox: "allow-scripts",
+                                srcdoc: "{page}"
+                            }
+                        }
+                    },
+                    Some(Err(err)) => rsx! {
+                        pre { class: "text-red-500 text-xs whitespace-pre-wrap", "{err}" }
+                    },
+                }
+            }
+        }
+    }
+}
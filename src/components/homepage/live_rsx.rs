@@ -0,0 +1,631 @@
+//! Interprets the `rsx!` of the homepage snippets in the browser, so they can be edited without
+//! recompiling.
+//!
+//! The snippet is parsed with `syn` and its `rsx!` with the same parser the `rsx!` macro uses, so
+//! anything the macro accepts parses here too. Like hot reloading, only the template is interpreted:
+//! elements, attributes, text and components defined in the same snippet. Rust expressions can't run
+//! without a compiler, so a formatted segment like `{count}` shows the initial value of what it names:
+//!
+//! - a `use_signal(|| value)` of the component or a `Signal::global(|| value)` static
+//! - a prop of the component
+//! - the value a server function in the snippet returns, for the `use_server_future` or `use_resource`
+//!   that calls it. A `match` on that future renders the arm that binds the value.
+//!
+//! Event handlers are dropped and anything else dynamic is shown as a placeholder. Values are escaped
+//! and tag or attribute names from strings are only written if they are valid names, so a snippet
+//! can't add script to the page.
+//!
+//! Only compiled with the `live-rsx` feature, which pulls `syn` and the rsx parser into the wasm.
+
+use dioxus_rsx::{
+    AttributeType, BodyNode, CallBody, ContentField, ElementAttrName, ElementAttrValue, ElementName,
+    FormattedSegmentType, IfmtInput, Segment,
+};
+use quote::ToTokens;
+use std::collections::HashMap;
+use std::fmt::Write;
+use syn::visit::Visit;
+
+/// An error in the rsx, with the line and column (both starting at 1) it was found at
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct RsxError {
+    pub(crate) message: String,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl std::fmt::Display for RsxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl From<syn::Error> for RsxError {
+    fn from(err: syn::Error) -> Self {
+        let start = err.span().start();
+        Self {
+            message: err.to_string(),
+            line: start.line,
+            column: start.column + 1,
+        }
+    }
+}
+
+/// Render the snippet to html.
+///
+/// The entry point is the `rsx!` of the `app` or `App` component, or the first component in the
+/// source if there is no such component. Every other component in the source can be used from it. A
+/// source without any functions is read as the body of an `rsx!`.
+pub(crate) fn render(source: &str) -> Result<String, RsxError> {
+    let file = syn::parse_file(source)?;
+    let snippet = Snippet::new(&file)?;
+
+    let body = match snippet.root() {
+        Some(root) => root.body.clone(),
+        None if snippet.functions.is_empty() => syn::parse_str::<CallBody>(source)?.roots,
+        None => Vec::new(),
+    };
+    let bindings = snippet.root().map(|root| root.bindings.clone()).unwrap_or_default();
+
+    let mut html = String::new();
+    Renderer {
+        snippet: &snippet,
+        depth: 0,
+        children: None,
+    }
+    .render_nodes(&body, &bindings, &mut html);
+    Ok(html)
+}
+
+/// The initial values of the names in scope, as they would be formatted
+type Bindings = HashMap<String, String>;
+
+/// A function in the snippet that returns `rsx!`
+struct Component {
+    name: String,
+    params: Vec<String>,
+    body: Vec<BodyNode>,
+    /// The initial values of the statics of the snippet, and of the signals, futures and match arm
+    /// bindings of the component
+    bindings: Bindings,
+}
+
+struct Snippet<'a> {
+    functions: Vec<&'a syn::ItemFn>,
+    components: Vec<Component>,
+}
+
+impl<'a> Snippet<'a> {
+    fn new(file: &'a syn::File) -> Result<Self, RsxError> {
+        let functions: Vec<&syn::ItemFn> = file
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::Item::Fn(function) => Some(function),
+                _ => None,
+            })
+            .collect();
+
+        let mut statics = Bindings::new();
+        for item in &file.items {
+            let (name, expr) = match item {
+                syn::Item::Static(item) => (&item.ident, &*item.expr),
+                syn::Item::Const(item) => (&item.ident, &*item.expr),
+                _ => continue,
+            };
+            if let Some(value) = initial_value(expr) {
+                statics.insert(name.to_string(), value);
+            }
+        }
+
+        let mut snippet = Self {
+            functions,
+            components: Vec::new(),
+        };
+        for function in snippet.functions.clone() {
+            let mut bindings = statics.clone();
+            bindings.extend(snippet.local_bindings(function));
+            let Some(rsx) = snippet.root_rsx(function, &mut bindings) else {
+                continue;
+            };
+            let body = syn::parse2::<CallBody>(rsx.tokens.clone())?.roots;
+            snippet.components.push(Component {
+                name: function.sig.ident.to_string(),
+                params: function
+                    .sig
+                    .inputs
+                    .iter()
+                    .filter_map(|input| match input {
+                        syn::FnArg::Typed(input) => pattern_name(&input.pat),
+                        syn::FnArg::Receiver(_) => None,
+                    })
+                    .collect(),
+                body,
+                bindings,
+            });
+        }
+        Ok(snippet)
+    }
+
+    fn root(&self) -> Option<&Component> {
+        self.components
+            .iter()
+            .find(|component| component.name == "app" || component.name == "App")
+            .or_else(|| self.components.first())
+    }
+
+    fn component(&self, name: &str) -> Option<&Component> {
+        self.components.iter().find(|component| component.name == name)
+    }
+
+    /// The initial values of the `let`s of a function
+    fn local_bindings(&self, function: &syn::ItemFn) -> Bindings {
+        let mut bindings = Bindings::new();
+        for statement in &function.block.stmts {
+            let syn::Stmt::Local(local) = statement else {
+                continue;
+            };
+            let (Some(name), Some(init)) = (pattern_name(&local.pat), &local.init) else {
+                continue;
+            };
+            let value = initial_value(&init.expr).or_else(|| self.future_value(&init.expr));
+            if let Some(value) = value {
+                bindings.insert(name, value);
+            }
+        }
+        bindings
+    }
+
+    /// The value a `use_server_future(function)` or `use_resource(function)` resolves to, if the
+    /// function is in the snippet and returns a literal
+    fn future_value(&self, expr: &syn::Expr) -> Option<String> {
+        let syn::Expr::Call(call) = strip_try(expr) else {
+            return None;
+        };
+        let hook = path_name(&call.func)?;
+        if !matches!(hook.as_str(), "use_server_future" | "use_resource") {
+            return None;
+        }
+        let function = path_name(call.args.first()?)?;
+        let function = self
+            .functions
+            .iter()
+            .find(|item| item.sig.ident == function)?;
+        match function.block.stmts.last()? {
+            syn::Stmt::Expr(expr, None) => initial_value(unwrap_call(expr, &["Ok", "Some"])),
+            _ => None,
+        }
+    }
+
+    /// The `rsx!` a function renders. If it matches on a future with a known value, that is the
+    /// `rsx!` of the arm that binds the value.
+    fn root_rsx(&self, function: &'a syn::ItemFn, bindings: &mut Bindings) -> Option<&'a syn::Macro> {
+        if let Some(syn::Stmt::Expr(syn::Expr::Match(matched), _)) = function.block.stmts.last() {
+            let future = match &*matched.expr {
+                syn::Expr::Call(call) if call.args.is_empty() => path_name(&call.func),
+                expr => path_name(expr),
+            };
+            if let Some(value) = future.and_then(|future| bindings.get(&future).cloned()) {
+                for arm in &matched.arms {
+                    if let Some(name) = bound_value(&arm.pat) {
+                        bindings.insert(name, value);
+                        return first_rsx(&arm.body);
+                    }
+                }
+            }
+        }
+
+        let mut finder = RsxFinder(None);
+        finder.visit_block(&function.block);
+        finder.0
+    }
+}
+
+/// Finds the first `rsx!` in a function
+struct RsxFinder<'a>(Option<&'a syn::Macro>);
+
+impl<'a> Visit<'a> for RsxFinder<'a> {
+    fn visit_macro(&mut self, mac: &'a syn::Macro) {
+        if self.0.is_none() && mac.path.is_ident("rsx") {
+            self.0 = Some(mac);
+        }
+    }
+}
+
+fn first_rsx(expr: &syn::Expr) -> Option<&syn::Macro> {
+    let mut finder = RsxFinder(None);
+    finder.visit_expr(expr);
+    finder.0
+}
+
+/// The name a pattern like `name` or `mut name` binds
+fn pattern_name(pattern: &syn::Pat) -> Option<String> {
+    match pattern {
+        syn::Pat::Ident(pattern) => Some(pattern.ident.to_string()),
+        syn::Pat::Type(pattern) => pattern_name(&pattern.pat),
+        _ => None,
+    }
+}
+
+/// The name a match arm like `Some(Ok(data))` binds the value of a future to
+fn bound_value(pattern: &syn::Pat) -> Option<String> {
+    match pattern {
+        syn::Pat::TupleStruct(pattern) => {
+            let name = pattern.path.segments.last()?.ident.to_string();
+            match (name.as_str(), pattern.elems.first()) {
+                ("Some" | "Ok", Some(inner)) if pattern.elems.len() == 1 => bound_value(inner),
+                _ => None,
+            }
+        }
+        // `None` parses as a binding too
+        syn::Pat::Ident(pattern) if !pattern.ident.to_string().starts_with(char::is_uppercase) => {
+            Some(pattern.ident.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// The name a `{name}` in the rsx refers to
+fn raw_expr_name(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(_) => path_name(expr),
+        syn::Expr::Block(block) => match block.block.stmts.as_slice() {
+            [syn::Stmt::Expr(expr, None)] => raw_expr_name(expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The last segment of a path expression
+fn path_name(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Path(path) => Some(path.path.segments.last()?.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn strip_try(expr: &syn::Expr) -> &syn::Expr {
+    match expr {
+        syn::Expr::Try(expr) => strip_try(&expr.expr),
+        syn::Expr::Paren(expr) => strip_try(&expr.expr),
+        expr => expr,
+    }
+}
+
+/// The argument of a call like `Ok(value)`, or the expression itself
+fn unwrap_call<'e>(expr: &'e syn::Expr, functions: &[&str]) -> &'e syn::Expr {
+    match expr {
+        syn::Expr::Call(call) if call.args.len() == 1 => match path_name(&call.func) {
+            Some(name) if functions.contains(&name.as_str()) => unwrap_call(&call.args[0], functions),
+            _ => expr,
+        },
+        expr => expr,
+    }
+}
+
+/// The value of a literal, or of the literal a signal starts with
+fn initial_value(expr: &syn::Expr) -> Option<String> {
+    match strip_try(expr) {
+        syn::Expr::Call(call) if call.args.len() == 1 => {
+            let function = path_name(&call.func)?;
+            match (function.as_str(), &call.args[0]) {
+                ("use_signal" | "global" | "use_hook", syn::Expr::Closure(closure)) => initial_value(&closure.body),
+                ("from" | "new", arg) => literal(arg),
+                _ => None,
+            }
+        }
+        expr => literal(expr),
+    }
+}
+
+/// The value of a literal number, bool or string expression, as it would be formatted
+fn literal(expr: &syn::Expr) -> Option<String> {
+    match expr {
+        syn::Expr::Lit(lit) => match &lit.lit {
+            syn::Lit::Str(lit) => Some(lit.value()),
+            syn::Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            syn::Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+            syn::Lit::Bool(lit) => Some(lit.value.to_string()),
+            syn::Lit::Char(lit) => Some(lit.value().to_string()),
+            _ => None,
+        },
+        syn::Expr::MethodCall(call)
+            if call.args.is_empty() && matches!(call.method.to_string().as_str(), "to_string" | "to_owned" | "into") =>
+        {
+            literal(&call.receiver)
+        }
+        syn::Expr::Paren(expr) => literal(&expr.expr),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal(expr).map(|value| format!("-{value}")),
+        _ => None,
+    }
+}
+
+/// Elements that can't have children, so they don't get a closing tag
+const VOID_ELEMENTS: &[&str] = &["area", "br", "col", "hr", "img", "input", "link", "meta", "source"];
+
+/// Attributes that are set on the element. Everything else is a style, like in dioxus.
+const HTML_ATTRIBUTES: &[&str] = &[
+    "alt", "checked", "class", "content", "dir", "disabled", "for", "height", "hidden", "href", "id",
+    "lang", "max", "min", "name", "placeholder", "readonly", "rel", "role", "src", "step", "style",
+    "tabindex", "target", "title", "type", "value", "width",
+];
+
+/// Components can render themselves, so stop before that runs away
+const MAX_DEPTH: usize = 32;
+
+struct Renderer<'a> {
+    snippet: &'a Snippet<'a>,
+    depth: usize,
+    /// The html of the children passed to the component that is being rendered
+    children: Option<String>,
+}
+
+impl Renderer<'_> {
+    fn render_nodes(&mut self, nodes: &[BodyNode], bindings: &Bindings, html: &mut String) {
+        for node in nodes {
+            self.render_node(node, bindings, html);
+        }
+    }
+
+    fn render_node(&mut self, node: &BodyNode, bindings: &Bindings, html: &mut String) {
+        match node {
+            BodyNode::Text(text) => render_text(text, bindings, html),
+            BodyNode::RawExpr(expr) => match raw_expr_name(expr) {
+                // The children of a component are already html
+                Some(name) if name == "children" && self.children.is_some() => {
+                    html.push_str(self.children.as_deref().unwrap_or_default())
+                }
+                Some(name) if bindings.contains_key(&name) => html.push_str(&escape(&bindings[&name])),
+                _ => placeholder(&format!("{{{}}}", tokens(expr)), html),
+            },
+            BodyNode::ForLoop(for_loop) => placeholder(
+                &format!("for {} in {}", tokens(&for_loop.pat), tokens(&for_loop.expr)),
+                html,
+            ),
+            BodyNode::IfChain(chain) => placeholder(&format!("if {}", tokens(&chain.cond)), html),
+            BodyNode::Component(component) => {
+                let name = component
+                    .name
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident.to_string())
+                    .unwrap_or_default();
+                let props: Vec<(String, Option<String>)> = component
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let value = match &field.content {
+                            ContentField::Shorthand(name) => bindings.get(&name.to_string()).cloned(),
+                            ContentField::ManExpr(expr) => evaluate(expr, bindings),
+                            ContentField::Formatted(text) => text_value(text, bindings),
+                            #[allow(unreachable_patterns)]
+                            _ => None,
+                        };
+                        (field.name.to_string(), value)
+                    })
+                    .collect();
+                self.render_component(&name, &props, &component.children, bindings, html)
+            }
+            BodyNode::Element(element) => {
+                let name = match &element.name {
+                    ElementName::Ident(name) => name.to_string(),
+                    ElementName::Custom(name) => name.value(),
+                };
+                if !is_html_name(&name) {
+                    placeholder(&format!("{name:?} {{}}"), html);
+                    return;
+                }
+                let mut styles = String::new();
+                _ = write!(html, "<{name}");
+                for attribute in &element.attributes {
+                    let AttributeType::Named(attribute) = attribute else {
+                        continue;
+                    };
+                    let name = match &attribute.attr.name {
+                        ElementAttrName::BuiltIn(name) => name.to_string().trim_start_matches("r#").to_string(),
+                        ElementAttrName::Custom(name) => name.value(),
+                    };
+                    let value = match &attribute.attr.value {
+                        ElementAttrValue::AttrLiteral(text) => text_value(text, bindings),
+                        ElementAttrValue::AttrExpr(expr) => evaluate(expr, bindings),
+                        // Event handlers and conditional attributes don't end up in the html
+                        _ => None,
+                    };
+                    let Some(value) = value else {
+                        continue;
+                    };
+                    let attribute = name.replace('_', "-");
+                    if !is_html_name(&attribute) {
+                        continue;
+                    }
+                    if HTML_ATTRIBUTES.contains(&attribute.as_str())
+                        || attribute.starts_with("aria-")
+                        || attribute.starts_with("data-")
+                    {
+                        _ = write!(html, " {attribute}=\"{}\"", escape(&value));
+                    } else {
+                        _ = write!(styles, "{attribute}: {value};");
+                    }
+                }
+                if !styles.is_empty() {
+                    _ = write!(html, " style=\"{}\"", escape(&styles));
+                }
+                html.push('>');
+                if VOID_ELEMENTS.contains(&name.as_str()) {
+                    return;
+                }
+                self.render_nodes(&element.children, bindings, html);
+                _ = write!(html, "</{name}>");
+            }
+        }
+    }
+
+    fn render_component(
+        &mut self,
+        name: &str,
+        props: &[(String, Option<String>)],
+        children: &[BodyNode],
+        bindings: &Bindings,
+        html: &mut String,
+    ) {
+        let component = self.snippet.component(name);
+        let Some(component) = component.filter(|_| self.depth < MAX_DEPTH) else {
+            placeholder(&format!("{name} {{}}"), html);
+            return;
+        };
+
+        let mut scope = component.bindings.clone();
+        for (prop, value) in props {
+            if let Some(value) = value.as_ref().filter(|_| component.params.contains(prop)) {
+                scope.insert(prop.clone(), value.clone());
+            }
+        }
+        let rendered_children = (!children.is_empty()).then(|| {
+            let mut rendered = String::new();
+            self.render_nodes(children, bindings, &mut rendered);
+            rendered
+        });
+
+        self.depth += 1;
+        let parent_children = std::mem::replace(&mut self.children, rendered_children);
+        self.render_nodes(&component.body, &scope, html);
+        self.children = parent_children;
+        self.depth -= 1;
+    }
+}
+
+fn evaluate(expr: &syn::Expr, bindings: &Bindings) -> Option<String> {
+    literal(expr).or_else(|| match expr {
+        syn::Expr::Path(_) => bindings.get(&path_name(expr)?).cloned(),
+        _ => None,
+    })
+}
+
+/// The value of a formatted segment, if everything it refers to is known
+fn segment_value(segment: &Segment, bindings: &Bindings) -> Option<String> {
+    match segment {
+        Segment::Literal(text) => Some(text.clone()),
+        Segment::Formatted(segment) => match &segment.segment {
+            FormattedSegmentType::Ident(name) => bindings.get(&name.to_string()).cloned(),
+            FormattedSegmentType::Expr(expr) => evaluate(expr, bindings),
+        },
+    }
+}
+
+fn text_value(text: &IfmtInput, bindings: &Bindings) -> Option<String> {
+    text.segments
+        .iter()
+        .map(|segment| segment_value(segment, bindings))
+        .collect()
+}
+
+/// Render text with the segments that aren't known as placeholders
+fn render_text(text: &IfmtInput, bindings: &Bindings, html: &mut String) {
+    for segment in &text.segments {
+        match (segment_value(segment, bindings), segment) {
+            (Some(value), _) => html.push_str(&escape(&value)),
+            (None, Segment::Formatted(segment)) => {
+                let expr = match &segment.segment {
+                    FormattedSegmentType::Ident(name) => name.to_string(),
+                    FormattedSegmentType::Expr(expr) => tokens(expr),
+                };
+                placeholder(&format!("{{{expr}}}"), html);
+            }
+            (None, Segment::Literal(_)) => {}
+        }
+    }
+}
+
+fn tokens(tokens: &impl ToTokens) -> String {
+    tokens.to_token_stream().to_string()
+}
+
+fn placeholder(expr: &str, html: &mut String) {
+    _ = write!(
+        html,
+        r#"<span class="rounded bg-gray-200 px-1 text-gray-500 font-mono text-xs">{}</span>"#,
+        escape(expr)
+    );
+}
+
+/// Whether a tag or attribute name from a string in the rsx can be written into the html as it is
+fn is_html_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | ':' | '.'))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[test]
+fn renders_the_homepage_snippets() {
+    let rendered: Vec<String> = super::snippets::SNIPPETS
+        .iter()
+        .map(|snippet| render(snippet.source).unwrap())
+        .collect();
+    assert_eq!(
+        rendered,
+        [
+            "<h1>High-Five counter: 0</h1><button>Up high!</button><button>Down low!</button>",
+            "Hello Alice, you are 30 years old!",
+            "<pre>Count: 0</pre>",
+            "<pre>The server says: Hello from the server!</pre>",
+            "Count: 0<button>Increment</button>",
+        ]
+    );
+}
+
+#[test]
+fn renders_what_the_rsx_macro_accepts() {
+    let source = r##"
+fn App() -> Element {
+    let data = "a";
+    rsx! {
+        // }
+        div { class: r#"b"#, "{data} {unknown}" }
+    }
+}
+"##;
+    assert_eq!(
+        render(source).unwrap(),
+        r#"<div class="b">a <span class="rounded bg-gray-200 px-1 text-gray-500 font-mono text-xs">{unknown}</span></div>"#
+    );
+
+    let error = render("fn app() -> Element {\n    rsx! {\n        div { class: }\n    }\n}").unwrap_err();
+    assert_eq!(error.line, 3);
+}
+
+#[test]
+fn escapes_names_and_props() {
+    let source = r#"
+fn App() -> Element {
+    rsx! {
+        div { "data-x\" onload=\"alert(1)": "a", "data-y": "<b>" }
+        Wrapper { children: "<img>" }
+    }
+}
+
+fn Wrapper(children: String) -> Element {
+    rsx! {
+        p { {children} }
+    }
+}
+"#;
+    assert_eq!(
+        render(source).unwrap(),
+        r#"<div data-y="&lt;b&gt;"></div><p>&lt;img&gt;</p>"#
+    );
+    assert!(is_html_name("my-element"));
+    assert!(!is_html_name("img src=x onerror=alert(1)"));
+}
@@ -4,6 +4,8 @@ use dioxus::prelude::*;
 pub(crate) mod call_to_action;
 pub(crate) mod featured_examples;
 pub(crate) mod hero;
+#[cfg(feature = "live-rsx")]
+pub(crate) mod live_rsx;
 pub(crate) mod snippets;
 pub(crate) mod value_add;
 
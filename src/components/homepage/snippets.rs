@@ -1,3 +1,5 @@
+#[cfg(feature = "live-rsx")]
+use super::live_rsx;
 use dioxus::prelude::*;
 use syntect_html::syntect_html_fs;
 
@@ -6,6 +8,7 @@ pub(crate) struct Snippet {
     pub(crate) title: &'static str,
     pub(crate) filename: &'static str,
     pub(crate) html: &'static str,
+    pub(crate) source: &'static str,
 }
 
 pub(crate) static SNIPPETS: &[Snippet] = &[
@@ -13,31 +16,37 @@ pub(crate) static SNIPPETS: &[Snippet] = &[
         title: "Hello world",
         filename: "readme.rs",
         html: syntect_html_fs!("./src/snippets/readme.rs"),
+        source: include_str!("../../snippets/readme.rs"),
     },
     Snippet {
         title: "Components",
         filename: "components.rs",
         html: syntect_html_fs!("./src/snippets/components.rs"),
+        source: include_str!("../../snippets/components.rs"),
     },
     Snippet {
         title: "Async",
         filename: "async.rs",
         html: syntect_html_fs!("./src/snippets/async_.rs"),
+        source: include_str!("../../snippets/async_.rs"),
     },
     Snippet {
         title: "Server",
         filename: "server.rs",
         html: syntect_html_fs!("./src/snippets/server.rs"),
+        source: include_str!("../../snippets/server.rs"),
     },
     Snippet {
         title: "Global State",
         filename: "global.rs",
         html: syntect_html_fs!("./src/snippets/global.rs"),
+        source: include_str!("../../snippets/global.rs"),
     },
 ];
 
 pub(crate) fn Snippets() -> Element {
     let mut selected_snippet = use_signal(|| 0);
+    let mut editing = use_signal(|| false);
 
     rsx! {
         section { class: "dark:text-white mt-4 -mx-4 sm:mx-0 lg:mt-0 lg:col-span-7 xl:col-span-6",
@@ -62,10 +71,23 @@ pub(crate) fn Snippets() -> Element {
                             }
                         }
                     }
+                    if cfg!(feature = "live-rsx") {
+                        button {
+                            class: "ml-auto mb-1 py-1 px-3 text-sm rounded text-gray-100 hover:bg-ghmetal",
+                            class: if editing() { "bg-ghmetal" },
+                            r#type: "button",
+                            onclick: move |_| editing.set(!editing()),
+                            if editing() { "Done" } else { "Edit" }
+                        }
+                    }
                     div { class: "absolute bottom-0 inset-x-0 h-px bg-neutral-500/30" }
                 }
 
-                div {
+                if editing() {
+                    LiveEditor { key: "{selected_snippet}", snippet: selected_snippet() }
+                }
+
+                div { class: if editing() { "hidden" },
                     for (id , snippet) in SNIPPETS.iter().enumerate() {
                         div {
                             key: "{snippet.title}",
@@ -85,6 +107,50 @@ pub(crate) fn Snippets() -> Element {
     }
 }
 
+/// Edit the source of a snippet next to a preview of what its rsx renders.
+///
+/// The preview keeps showing the last source that parsed while the current one has an error.
+#[cfg(feature = "live-rsx")]
+#[component]
+fn LiveEditor(snippet: usize) -> Element {
+    let mut source = use_signal(|| SNIPPETS[snippet].source.to_string());
+    let rendered = use_memo(move || live_rsx::render(&source()));
+    let mut last_good = use_signal(|| live_rsx::render(SNIPPETS[snippet].source).unwrap_or_default());
+
+    use_effect(move || {
+        if let Ok(html) = rendered() {
+            last_good.set(html);
+        }
+    });
+
+    rsx! {
+        div { class: "flex flex-col lg:flex-row min-h-0",
+            textarea {
+                class: "font-mono text-sm w-full lg:w-1/2 min-h-[40vh] p-4 bg-ghmetal text-gray-100 outline-none resize-none",
+                spellcheck: false,
+                value: "{source}",
+                oninput: move |evt| source.set(evt.value())
+            }
+            div { class: "relative w-full lg:w-1/2 min-h-[20vh] p-4 bg-white text-gray-800 lg:border-l border-neutral-500/30",
+                div { dangerous_inner_html: "{last_good}" }
+                if let Err(err) = rendered() {
+                    pre { class: "absolute bottom-0 inset-x-0 m-2 p-2 rounded bg-red-100 text-red-700 text-xs whitespace-pre-wrap",
+                        "{err}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Without the `live-rsx` feature the snippets have no "Edit" button, so the editor never opens
+#[cfg(not(feature = "live-rsx"))]
+#[component]
+fn LiveEditor(snippet: usize) -> Element {
+    _ = snippet;
+    None
+}
+
 // div { class: "relative overflow-hidden flex bg-neutral-800 max-h-[60vh] sm:max-h-[none] sm:rounded-xl dark:bg-neutral-900/70 dark:backdrop-blur dark:ring-1 dark:ring-inset dark:ring-white/10 shadow-3xl",
 // div { class: "relative overflow-hidden flex bg-neutral-800 h-[31.625rem] max-h-[60vh] sm:max-h-[none] sm:rounded-xl lg:h-[34.6875rem] xl:h-[31.625rem] dark:bg-neutral-900/70 dark:backdrop-blur dark:ring-1 dark:ring-inset dark:ring-white/10 shadow-3xl",
 // div { class: "relative w-full flex flex-col",
@@ -1,23 +1,18 @@
-use crate::docs::LAZY_BOOK;
+use crate::docs::router_05::BOOK_DIR;
+use crate::docs::router_05::LANGUAGES;
+use crate::docs::router_05::RENDERED_PAGES;
+use crate::docs::router_05::SUMMARY;
 use crate::*;
 use dioxus::prelude::*;
 use dioxus_material_icons::MaterialIcon;
 use dioxus_material_icons::MaterialIconColor;
-use mdbook_shared::Page;
-use mdbook_shared::SummaryItem;
 
 pub(crate) static HIGHLIGHT_DOCS_LAYOUT: GlobalSignal<bool> = Signal::global(|| false);
 pub(crate) static SHOW_SIDEBAR: GlobalSignal<bool> = Signal::global(|| false);
 pub(crate) static HIGHLIGHT_DOCS_CONTENT: GlobalSignal<bool> = Signal::global(|| false);
 
-/// The Markdown file path needs to be appended to this, including the first slash!
-const GITHUB_API_URL: &str =
-    "https://api.github.com/repos/DioxusLabs/docsite/contents/docs-src/0.5/en";
-/// Use this URL while loading the file-specific URL.
-const GITHUB_EDIT_PAGE_FALLBACK_URL: &str = "https://github.com/DioxusLabs/docsite";
-/// The Markdown file path needs to be appended to this, including the first slash!
-const GITHUB_EDIT_PAGE_EDIT_URL: &str =
-    "https://github.com/DioxusLabs/docsite/edit/main/docs-src/0.5/en";
+/// The docsite repository on GitHub
+const GITHUB_REPO_URL: &str = "https://github.com/DioxusLabs/docsite";
 
 #[component]
 pub(crate) fn Learn() -> Element {
@@ -33,19 +28,18 @@ pub(crate) fn Learn() -> Element {
             div { class: "flex flex-row justify-center dark:text-[#dee2e6] font-light",
                 LeftNav {}
                 Content {}
-                RightNav {}
+                RightNav {
+                    page: use_book(),
+                    headings: page_headings(&use_route()),
+                    source: route_page(&use_route()).map(page_source)
+                }
             }
+            Playground {}
         }
     }
 }
 
 fn LeftNav() -> Element {
-    let chapters = vec![
-        &LAZY_BOOK.summary.prefix_chapters,
-        &LAZY_BOOK.summary.numbered_chapters,
-        &LAZY_BOOK.summary.suffix_chapters,
-    ];
-
     // We use this to remove the spacing between "Introduction" and "Getting Started"
     // TODO: Make this depend on if the chapter has any links.
     let mut keep_bottom_spacing = false;
@@ -57,7 +51,7 @@ fn LeftNav() -> Element {
                 class: "bg-white dark:bg-ideblack lg:bg-inherit pl-6 pb-32 z-20 text-base lg:block top-28 lg:-ml-3.5 pr-2 w-[calc(100%-1rem)] md:w-60 lg:text-[14px] text-navy content-startleading-5 ",
                 class: if HIGHLIGHT_DOCS_LAYOUT() { "border border-green-600 rounded-md" },
                 class: if SHOW_SIDEBAR() { "min-w-full" } else { "hidden" },
-                for chapter in chapters.into_iter().flatten().filter(|chapter| chapter.maybe_link().is_some()) {
+                for chapter in SUMMARY {
                     SidebarSection { chapter, keep_bottom_spacing }
                     {keep_bottom_spacing = true}
                 }
@@ -106,10 +100,10 @@ fn DocVersionNav() -> Element {
 ///
 /// This renders a single section
 #[component]
-fn SidebarSection(chapter: &'static SummaryItem<BookRoute>, keep_bottom_spacing: bool) -> Element {
-    let link = chapter.maybe_link()?;
+fn SidebarSection(chapter: &'static Chapter, keep_bottom_spacing: bool) -> Element {
+    let language = use_language();
 
-    let sections = link
+    let sections = chapter
         .nested_items
         .iter()
         .map(|chapter| rsx! { SidebarChapter { chapter: chapter } });
@@ -118,11 +112,13 @@ fn SidebarSection(chapter: &'static SummaryItem<BookRoute>, keep_bottom_spacing:
         div { 
             class: "full-chapter",
             class: if keep_bottom_spacing { "pb-4 mb-6" },
-            if let Some(url) = &link.location {
+            if let Some(url) = chapter.location {
                 Link {
                     onclick: move |_| *SHOW_SIDEBAR.write() = false,
-                    to: Route::Docs { child: *url },
-                    h3 { class: "font-semibold mb-2 hover:text-sky-500 dark:hover:text-sky-400", "{link.name}" }
+                    to: docs_route(url, language),
+                    h3 { class: "font-semibold mb-2 hover:text-sky-500 dark:hover:text-sky-400",
+                        {language.chapter_name(url, chapter.name)}
+                    }
                 }
             }
             ul { class: "ml-1", {sections} }
@@ -131,9 +127,9 @@ fn SidebarSection(chapter: &'static SummaryItem<BookRoute>, keep_bottom_spacing:
 }
 
 #[component]
-fn SidebarChapter(chapter: &'static SummaryItem<BookRoute>) -> Element {
-    let link = chapter.maybe_link()?;
-    let url = link.location.as_ref().unwrap();
+fn SidebarChapter(chapter: &'static Chapter) -> Element {
+    let language = use_language();
+    let url = chapter.location?;
     let mut list_toggle = use_signal(|| false);
 
     // current route of the browser, trimmed to the book url
@@ -142,15 +138,15 @@ fn SidebarChapter(chapter: &'static SummaryItem<BookRoute>) -> Element {
     // for instance, if the current page is /docs/0.5/en/learn/overview
     // then we want to show the dropdown for /docs/0.5/en/learn
     let show_dropdown = list_toggle() || book_url.starts_with(&*url.to_string());
-    let show_chevron = !link.nested_items.is_empty();
+    let show_chevron = !chapter.nested_items.is_empty();
 
     if show_chevron {
         rsx! {
             li { class: "rounded-md hover:text-sky-500 dark:hover:text-sky-400",
                 Link {
                     onclick: move |_| *SHOW_SIDEBAR.write() = false,
-                    to: Route::Docs { child: *url },
-                    "{link.name}"
+                    to: docs_route(url, language),
+                    {language.chapter_name(url, chapter.name)}
                 }
                 button {
                     onclick: move |_| list_toggle.toggle(),
@@ -163,7 +159,7 @@ fn SidebarChapter(chapter: &'static SummaryItem<BookRoute>) -> Element {
             }
             if show_dropdown {
                 ul { class: "border-l border-gray-300 m-2 px-2 space-y-1",
-                    for chapter in link.nested_items.iter() {
+                    for chapter in chapter.nested_items {
                         SidebarChapter { chapter }
                     }
                 }
@@ -175,65 +171,115 @@ fn SidebarChapter(chapter: &'static SummaryItem<BookRoute>) -> Element {
 }
 
 #[component]
-fn LocationLink(chapter: &'static SummaryItem<BookRoute>) -> Element {
-    let book_url = use_book().to_string();
+fn LocationLink(chapter: &'static Chapter) -> Element {
+    let language = use_language();
+    let route = use_route();
+    let book_url = book_page(&route).to_string();
+
+    let current_section = scroll_spy::use_current_section();
 
-    let link = chapter.maybe_link()?;
-    let url = link.location.as_ref().unwrap();
+    let url = chapter.location?;
+
+    // Show the section that is being read under the link to the current page
+    let section_title = (book_url == url.to_string())
+        .then(|| current_section.read().clone())
+        .flatten()
+        .and_then(|id| page_headings(&route).iter().find(|heading| heading.id == id))
+        .map(|heading| heading.title);
 
     rsx! {
         Link {
             onclick: move |_| *SHOW_SIDEBAR.write() = false,
-            to: Route::Docs { child: *url },
+            to: docs_route(url, language),
             li {
                 class: "rounded-md hover:text-sky-500 dark:hover:text-sky-400",
                 class: if book_url.starts_with(&*url.to_string()) { "text-sky-500 dark:text-sky-400" },
-                "{link.name}"
+                {language.chapter_name(url, chapter.name)}
+                if let Some(title) = section_title {
+                    span { class: "block pl-2 text-xs text-gray-500 dark:text-gray-400", "{title}" }
+                }
             }
         }
     }
 }
 
-// Todo: wire this up to the sections of the current page and a scroll controller
-fn RightNav() -> Element {
-    let page = use_book();
-
-    let padding_map = ["", "", "pl-2", "pl-4", "pl-6", "pl-8"];
-    let page_url = use_memo(move || page.to_string());
-
-    let edit_github_url = use_resource(move || async move {
-        // This is the URL for the file if that file is not a directory that uses /index.md
-        // page_url starts with '/', so we don't need to worry about that
-        let github_api_url = format!("{GITHUB_API_URL}{page_url}.md");
-        // If the file is not found, that means that we have to use /index.md
-        if reqwest::get(github_api_url).await.unwrap().status() == reqwest::StatusCode::NOT_FOUND {
-            format!("{GITHUB_EDIT_PAGE_EDIT_URL}{page_url}/index.md")
-        } else {
-            format!("{GITHUB_EDIT_PAGE_EDIT_URL}{page_url}.md")
-        }
-    });
+#[component]
+fn RightNav(
+    page: ReadOnlySignal<BookRoute>,
+    headings: ReadOnlySignal<&'static [Heading]>,
+    /// The markdown file of the page in the language it is shown in, relative to the repo
+    source: ReadOnlySignal<Option<String>>,
+) -> Element {
+    scroll_spy::use_scroll_spy(move || headings().iter().map(|heading| heading.id.to_string()).collect());
+    let page = page();
+
+    let source = source();
+    let edit_url = source
+        .as_ref()
+        .map(|path| format!("{GITHUB_REPO_URL}/edit/main/{path}"));
+    let source_url = source
+        .as_ref()
+        .map(|path| format!("{GITHUB_REPO_URL}/blob/main/{path}"));
+    let issue_url = {
+        let page_url = Route::Docs { child: page }.to_string();
+        let query = form_urlencoded::Serializer::new(String::new())
+            .append_pair("title", &format!("Issue on {page_url}"))
+            .append_pair("body", &format!("Page: https://dioxuslabs.com{page_url}\n\n"))
+            .finish();
+        format!("{GITHUB_REPO_URL}/issues/new?{query}")
+    };
 
-    // That might be a naive approach, but it's the easiest
     rsx! {
         div {
             class: "overflow-y-auto hidden xl:block top-28 ml-12 h-full md:text-[14px] leading-5 text-navy dark:text-[#dee2e6] docs-right-sidebar w-48 sticky",
             class: if HIGHLIGHT_DOCS_LAYOUT() { "border border-green-600 rounded-md" },
             h2 { class: "pb-4 font-semibold", "On this page" }
             ul {
-                for section in page.sections().iter().skip(1) {
-                    li { class: "pb-2 {padding_map[section.level-1]}",
-                        a { class: "hover:text-sky-500 dark:hover:text-sky-400", href: "?phantom={section.id}#{section.id}", "{section.title}" }
-                    }
+                for heading in headings().iter().skip(1) {
+                    SectionLink { id: heading.id, title: heading.title, level: heading.level }
                 }
             }
-            h2 { class: "py-4 font-semibold",
-                match edit_github_url.cloned() {
-                    Some(url) => rsx!(a { class: "hover:text-sky-500 dark:hover:text-sky-400", href: "{url}", "Edit this page!" }),
-                    None => rsx!(a { href: "{GITHUB_EDIT_PAGE_FALLBACK_URL}", "Edit this page!" })
+            h2 { class: "pt-4 pb-2 font-semibold", "Contribute" }
+            ul {
+                if let Some(url) = edit_url {
+                    li { class: "pb-2",
+                        a { class: "hover:text-sky-500 dark:hover:text-sky-400", href: "{url}", "Edit this page!" }
+                    }
+                }
+                if let Some(url) = source_url {
+                    li { class: "pb-2",
+                        a { class: "hover:text-sky-500 dark:hover:text-sky-400", href: "{url}", "View source" }
+                    }
+                }
+                li { class: "pb-2",
+                    a { class: "hover:text-sky-500 dark:hover:text-sky-400", href: "{issue_url}", "Report an issue for this page" }
                 }
             }
             h2 { class: "py-4 font-semibold", "Go to version" }
             DocVersionNav {}
+            h2 { class: "py-4 font-semibold", "Language" }
+            LanguageNav {}
+        }
+    }
+}
+
+/// A link to a heading of the current page, highlighted while that section is being read
+#[component]
+fn SectionLink(id: String, title: String, level: usize) -> Element {
+    let padding_map = ["", "", "pl-2", "pl-4", "pl-6", "pl-8"];
+    let current_section = scroll_spy::use_current_section();
+    let active = current_section.read().as_deref() == Some(id.as_str());
+
+    rsx! {
+        li { class: "pb-2 {padding_map[level - 1]}",
+            a {
+                class: "hover:text-sky-500 dark:hover:text-sky-400",
+                class: if active { "text-sky-500 dark:text-sky-400 font-normal" },
+                href: "#{id}",
+                prevent_default: "onclick",
+                onclick: move |_| scroll_spy::scroll_to_section(&id),
+                "{title}"
+            }
         }
     }
 }
@@ -251,26 +297,115 @@ fn Content() -> Element {
                         ".markdown-body li {{ display: list-item; }}"
                         ".markdown-body button {{ display: inline-block; background-color: rgba(209, 213, 219, 0.3); border-radius: 0.25rem; padding: 0.25rem 0.5rem; border: 1px solid; margin: 0.25rem; }}"
                         ".markdown-body .header {{ color: inherit }}"
+                        ".markdown-body :is(h1, h2, h3, h4, h5, h6) {{ scroll-margin-top: 6rem; }}"
                     }
-                    article { class: "markdown-body", Outlet::<Route> {} }
+                    article { class: "markdown-body", DocsPage {} }
+                    ContentFooter { page: use_book(), lang: use_language().code }
+                }
+            }
+        }
+    }
+}
 
-                    // todo: we want left-right buttons to go between pages in the docs
-                    // ContentFooter {}
+/// Every page of the book in reading order: prefix, numbered and suffix chapters, depth first
+static BOOK_PAGES: once_cell::sync::Lazy<Vec<&'static Chapter>> =
+    once_cell::sync::Lazy::new(|| {
+        fn flatten(chapters: &'static [Chapter], pages: &mut Vec<&'static Chapter>) {
+            for chapter in chapters {
+                if chapter.location.is_some() {
+                    pages.push(chapter);
                 }
+                flatten(chapter.nested_items, pages);
+            }
+        }
+
+        let mut pages = Vec::new();
+        flatten(SUMMARY, &mut pages);
+        pages
+    });
+
+/// The pages before and after the given page in reading order
+fn neighbouring_pages(page: BookRoute) -> (Option<&'static Chapter>, Option<&'static Chapter>) {
+    let Some(index) = BOOK_PAGES.iter().position(|link| link.location == Some(page)) else {
+        return (None, None);
+    };
+    let previous = index.checked_sub(1).and_then(|index| BOOK_PAGES.get(index));
+    let next = BOOK_PAGES.get(index + 1);
+    (previous.copied(), next.copied())
+}
+
+/// The names of the chapters leading to a page in the summary, starting at the top-level section and
+/// ending with the page itself
+pub(crate) fn breadcrumb(page: BookRoute) -> Vec<&'static str> {
+    fn find(chapters: &'static [Chapter], page: BookRoute, trail: &mut Vec<&'static str>) -> bool {
+        for chapter in chapters {
+            trail.push(chapter.name);
+            if chapter.location == Some(page) || find(chapter.nested_items, page, trail) {
+                return true;
             }
+            trail.pop();
         }
+        false
     }
+
+    let mut trail = Vec::new();
+    find(SUMMARY, page, &mut trail);
+    trail
 }
 
-fn ContentFooter() -> Element {
+/// Links to the previous and next page of the book, also bound to the left and right arrow keys
+#[component]
+fn ContentFooter(page: ReadOnlySignal<BookRoute>, lang: ReadOnlySignal<&'static str>) -> Element {
+    let navigator = use_navigator();
+    let current_language = move || language(&lang()).unwrap_or(&LANGUAGES[0]);
+
+    let go_to = move |neighbour: Option<&'static Chapter>| {
+        // Don't steal the arrow keys from the search modal
+        if SHOW_SEARCH() {
+            return;
+        }
+        if let Some(url) = neighbour.and_then(|link| link.location) {
+            navigator.push(docs_route(url, current_language()));
+        }
+    };
+    shortcut::use_shortcut(Key::ArrowLeft, Modifiers::empty(), move || {
+        go_to(neighbouring_pages(page()).0)
+    });
+    shortcut::use_shortcut(Key::ArrowRight, Modifiers::empty(), move || {
+        go_to(neighbouring_pages(page()).1)
+    });
+
+    let (previous, next) = neighbouring_pages(page());
+
     rsx! {
-       div {
-            class: "chapter-nav",
-            button {
-                "left"
+        div { class: "chapter-nav w-full flex flex-row justify-between gap-4 pt-12 mt-12 border-t border-gray-200 dark:border-gray-700",
+            if let Some(link) = previous {
+                Link {
+                    to: docs_route(link.location.unwrap(), current_language()),
+                    class: "flex flex-row items-center p-4 rounded-md border border-gray-200 dark:border-gray-700 hover:text-sky-500 dark:hover:text-sky-400",
+                    MaterialIcon { name: "chevron_left", color: MaterialIconColor::Custom("gray".to_string()) }
+                    div { class: "flex flex-col",
+                        span { class: "text-xs text-gray-500", "Previous" }
+                        span { class: "font-semibold",
+                            {current_language().chapter_name(link.location.unwrap(), link.name)}
+                        }
+                    }
+                }
+            } else {
+                div {}
             }
-            button {
-                "right"
+            if let Some(link) = next {
+                Link {
+                    to: docs_route(link.location.unwrap(), current_language()),
+                    class: "flex flex-row items-center p-4 rounded-md border border-gray-200 dark:border-gray-700 hover:text-sky-500 dark:hover:text-sky-400 text-right",
+                    div { class: "flex flex-col",
+                        span { class: "text-xs text-gray-500", "Next" }
+                        span { class: "font-semibold",
+                            {current_language().chapter_name(link.location.unwrap(), link.name)}
+                        }
+                    }
+                    MaterialIcon { name: "chevron_right", color: MaterialIconColor::Custom("gray".to_string()) }
+                }
             }
         }
     }
@@ -295,19 +430,211 @@ fn BreadCrumbs() -> Element {
 /// Get the book URL from the current URL
 /// Ignores language and version (for now)
 fn use_book() -> BookRoute {
-    let route = use_route();
+    book_page(&use_route())
+}
+
+/// The English page of the book a docs route shows, in any language
+pub(crate) fn book_page(route: &Route) -> BookRoute {
     match route {
-        Route::Docs { child } => child,
+        Route::Docs { child } => *child,
+        Route::DocsTranslated { child } => child.page_url().parse().unwrap_or_default(),
         _ => unreachable!(),
     }
 }
 
-fn default_page() -> &'static Page<BookRoute> {
-    let id = LAZY_BOOK
-        .page_id_mapping
-        .get(&BookRoute::default())
-        .unwrap();
-    LAZY_BOOK.pages.get(id.0).unwrap()
+/// A heading of a page of the docs
+#[derive(PartialEq, Debug)]
+pub(crate) struct Heading {
+    pub(crate) level: usize,
+    pub(crate) title: &'static str,
+    pub(crate) id: &'static str,
+}
+
+/// A page of the docs in one language, rendered to rsx by `build.rs`
+pub(crate) struct RenderedPage {
+    pub(crate) language: &'static str,
+    /// The markdown file, relative to the folder of the language
+    pub(crate) source: &'static str,
+    pub(crate) headings: &'static [Heading],
+    pub(crate) render: fn() -> Element,
+}
+
+/// The rendered page of the book in a language, if it has been translated to it
+fn rendered_page(language: &str, page: BookRoute) -> Option<&'static RenderedPage> {
+    let source = page.source_path()?;
+    RENDERED_PAGES
+        .iter()
+        .find(|rendered| rendered.language == language && rendered.source == source)
+}
+
+/// The page a docs route shows: the translated page, or the English one if there is none
+fn route_page(route: &Route) -> Option<&'static RenderedPage> {
+    let page = book_page(route);
+    let language = match route {
+        Route::DocsTranslated { child } => child.language(),
+        _ => DEFAULT_LANGUAGE,
+    };
+    rendered_page(language, page).or_else(|| rendered_page(DEFAULT_LANGUAGE, page))
+}
+
+/// The markdown file a page is rendered from, relative to the root of the repo. Translations live
+/// next to the English book, e.g. `docs-src/0.5/pt-br` for `docs-src/0.5/en`.
+fn page_source(page: &RenderedPage) -> String {
+    let version_dir = BOOK_DIR.rsplit_once('/').map_or(BOOK_DIR, |(dir, _)| dir);
+    format!("{version_dir}/{}/{}", page.language, page.source)
+}
+
+/// The headings of the page a docs route shows, in the language it is read in
+pub(crate) fn page_headings(route: &Route) -> &'static [Heading] {
+    route_page(route).map_or(&[], |page| page.headings)
+}
+
+/// A chapter of the `SUMMARY.md` of the book, shown in the sidebar
+#[derive(PartialEq)]
+pub(crate) struct Chapter {
+    /// The title of the chapter in English
+    pub(crate) name: &'static str,
+    /// The page of the chapter, if it isn't a draft
+    pub(crate) location: Option<BookRoute>,
+    pub(crate) nested_items: &'static [Chapter],
+}
+
+/// A language the docs are declared in by the book.toml
+pub(crate) struct Language {
+    /// The code of the language used in urls, e.g. "pt-br"
+    pub(crate) code: &'static str,
+    /// The name of the language in that language
+    pub(crate) name: &'static str,
+    /// The markdown files that have been translated to this language
+    pub(crate) sources: &'static [&'static str],
+    /// The chapter titles of the `SUMMARY.md` of this language, by markdown file
+    pub(crate) titles: &'static [(&'static str, &'static str)],
+}
+
+impl Language {
+    fn is_default(&self) -> bool {
+        self.code == DEFAULT_LANGUAGE
+    }
+
+    /// Whether this page has been translated to this language
+    fn has_page(&self, page: BookRoute) -> bool {
+        page.source_path()
+            .map_or(false, |source| self.sources.contains(&source))
+    }
+
+    /// The title of a chapter in this language, or the English one if the summary doesn't translate it
+    fn chapter_name(&self, page: BookRoute, english: &'static str) -> &'static str {
+        page.source_path()
+            .and_then(|source| self.titles.iter().find(|(path, _)| *path == source))
+            .map_or(english, |(_, title)| *title)
+    }
+}
+
+/// The language every page of the docs is written in
+pub(crate) const DEFAULT_LANGUAGE: &str = "en";
+
+pub(crate) fn language(code: &str) -> Option<&'static Language> {
+    LANGUAGES.iter().find(|language| language.code == code)
+}
+
+/// Get the language the docs are being read in from the current URL
+fn use_language() -> &'static Language {
+    let route = use_route();
+    let code = match &route {
+        Route::DocsTranslated { child } => child.language(),
+        _ => DEFAULT_LANGUAGE,
+    };
+    language(code).unwrap_or(&LANGUAGES[0])
+}
+
+fn url_segments(url: &str) -> Vec<String> {
+    url.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The route of a page of the book in the given language
+fn docs_route(page: BookRoute, language: &Language) -> Route {
+    if language.is_default() {
+        return Route::Docs { child: page };
+    }
+    // Parsing the url picks the translated page over the English fallback if there is one
+    let url = format!("/{}{}", language.code, page.to_string().trim_end_matches('/'));
+    let child = url
+        .parse()
+        .unwrap_or_else(|_| TranslatedRoute::Untranslated {
+            lang: language.code.to_string(),
+            segments: url_segments(&page.to_string()),
+        });
+    Route::DocsTranslated { child }
+}
+
+/// The English page a translated url points to, or the index if there is no such page
+pub(crate) fn english_page(segments: &[String]) -> BookRoute {
+    format!("/{}", segments.join("/"))
+        .parse()
+        .unwrap_or_default()
+}
+
+/// The page being read, in the language of the url. Pages that haven't been translated to that
+/// language fall back to English.
+fn DocsPage() -> Element {
+    let route = use_route();
+    let language = use_language();
+    if let Route::DocsTranslated {
+        child: TranslatedRoute::Untranslated { lang, segments },
+    } = &route
+    {
+        if self::language(lang).is_none() {
+            return rsx! { Err404 { segments: [vec![lang.clone()], segments.clone()].concat() } };
+        }
+    }
+    let rendered = route_page(&route)?;
+
+    rsx! {
+        if rendered.language != language.code {
+            div { class: "w-full rounded-md border border-sky-500 bg-sky-50 dark:bg-sky-900/20 p-4 mb-8 text-sky-800 dark:text-sky-200",
+                "This page hasn't been translated to {language.name} yet, so you are reading the English version. "
+                Link {
+                    to: NavigationTarget::<Route>::External(format!(
+                        "https://github.com/DioxusLabs/docsite/tree/main/docs-src/0.5/{}",
+                        language.code
+                    )),
+                    new_tab: true,
+                    class: "underline font-semibold",
+                    "Help translate it!"
+                }
+            }
+        }
+        {(rendered.render)()}
+    }
+}
+
+/// Switch between the languages of the docs, staying on the same page
+fn LanguageNav() -> Element {
+    let page = use_book();
+    let current = use_language();
+
+    rsx! {
+        div { class: "pb-4",
+            ul { class: "pl-2",
+                for language in LANGUAGES {
+                    li { class: "m-1 rounded-md pl-2",
+                        Link {
+                            to: docs_route(page, language),
+                            class: "hover:text-sky-500 dark:hover:text-sky-400",
+                            class: if language.code == current.code { "text-sky-500 dark:text-sky-400" },
+                            "{language.name}"
+                            if !language.is_default() && !language.has_page(page) {
+                                span { class: "pl-1 text-xs text-gray-500", "(English)" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[component]
@@ -325,3 +652,35 @@ pub(crate) fn DocsO4(segments: Vec<String>) -> Element {
     navigator.push(route);
     None
 }
+
+#[test]
+fn translated_pages_fall_back_to_english() {
+    let translated: Route = "/learn/0.5/pt-br".parse().unwrap();
+    assert!(matches!(translated, Route::DocsTranslated { .. }));
+    let page = route_page(&translated).unwrap();
+    assert_eq!(page_source(page), "docs-src/0.5/pt-br/index.md");
+
+    let untranslated: Route = "/learn/0.5/pt-br/guide".parse().unwrap();
+    let page = route_page(&untranslated).unwrap();
+    assert_eq!(page_source(page), "docs-src/0.5/en/guide/index.md");
+
+    assert!(crate::docs::router_05::TRANSLATION_SEARCH_CHUNKS
+        .iter()
+        .any(|chunk| chunk.name == "search-pt-br"));
+}
+
+#[test]
+fn summary_is_read_like_mdbook() {
+    assert_eq!(SUMMARY[0].name, "Introduction");
+    assert_eq!(SUMMARY[0].location, Some(BookRoute::Index {}));
+    assert_eq!(BookRoute::default(), BookRoute::Index {});
+
+    let page: Route = "/learn/0.5/reference/mobile/apis".parse().unwrap();
+    assert_eq!(
+        breadcrumb(book_page(&page)),
+        ["Reference", "Mobile", "APIs"]
+    );
+
+    // Pages the summary doesn't link to don't get a route
+    assert!("/reference/tui".parse::<BookRoute>().is_err());
+}
@@ -2,7 +2,6 @@ use crate::*;
 use dioxus::html::input_data::keyboard_types::Key;
 use dioxus::prelude::*;
 use dioxus_material_icons::{MaterialIcon, MaterialIconColor};
-use std::ops::Deref;
 
 pub(crate) static HIGHLIGHT_NAV_LAYOUT: GlobalSignal<bool> = Signal::global(|| false);
 pub(crate) static SHOW_NAV: GlobalSignal<bool> = Signal::global(|| false);
@@ -157,11 +156,40 @@ fn Search() -> Element {
     }
 }
 
-type Results = Result<Vec<dioxus_search::SearchResult<Route>>, stork_lib::SearchError>;
+type Results = Result<Vec<dioxus_search::SearchResult<Route>>, search::SearchError>;
+
+/// How long to wait before searching again while the index is still loading
+const SEARCH_RETRY_MS: u32 = 1000;
+/// How many times to search again on our own before we leave it to the retry button
+const SEARCH_AUTO_RETRIES: u32 = 5;
+
+/// Results that belong to the same top-level section of the site, in the order they ranked
+#[derive(Clone, PartialEq)]
+struct SearchGroup {
+    name: &'static str,
+    results: Vec<dioxus_search::SearchResult<Route>>,
+}
 
 fn SearchModal() -> Element {
     let mut search_text = use_signal(String::new);
-    let mut results = use_signal(|| SEARCH_INDEX.search(&search_text.read()));
+    let mut results = use_signal(|| search::search(&search_text.read()));
+    let mut attempts = use_signal(|| 0);
+    // The index of the selected result, counted across all groups in the order they are shown
+    let mut selected = use_signal(|| 0);
+    let filters = use_signal(search::SearchFilters::default);
+
+    let groups = use_memo(move || match results.read().as_ref() {
+        Ok(results) => {
+            let filters = filters();
+            let results: Vec<_> = results
+                .iter()
+                .filter(|result| filters.matches(&result.route))
+                .cloned()
+                .collect();
+            group_results(&results)
+        }
+        Err(_) => Vec::new(),
+    });
 
     let mut last_key_press = use_signal(|| {
         #[cfg(not(target_arch = "wasm32"))]
@@ -172,21 +200,47 @@ fn SearchModal() -> Element {
     use_resource(move || {
         async move {
             _ = search_text();
+            _ = attempts();
+
+            // Fetch the chunks of the index that aren't loaded yet, and the suggestions that go with it
+            search::load_chunks().await;
+            search_log::load_suggestions().await;
 
             // debounce the search
             if *last_key_press.read() - js_sys::Date::now() > 100. {
-                results.set(SEARCH_INDEX.search(&search_text.read()));
+                results.set(search::search(&search_text.read()));
                 last_key_press.set(js_sys::Date::now());
             } else {
                 gloo_timers::future::TimeoutFuture::new(100).await;
-                results.set(SEARCH_INDEX.search(&search_text.read()));
+                results.set(search::search(&search_text.read()));
+            }
+            selected.set(0);
+
+            let found = results.peek().as_ref().map(|results| results.len()).ok();
+            if let Some(found) = found {
+                if found < search_log::LOW_RESULT_COUNT && *search_log::RECORD_SEARCH_MISSES.peek() {
+                    // Wait for the reader to stop typing. If they keep going, this task is replaced
+                    gloo_timers::future::TimeoutFuture::new(search_log::RECORD_DELAY_MS).await;
+                    let query = search_text.peek().clone();
+                    if !query.trim().is_empty() {
+                        _ = search_log::record_search_miss(query, found).await;
+                    }
+                }
+            }
+
+            // The index may still be loading, so try again in a bit instead of giving up
+            if results.peek().is_err() && *attempts.peek() < SEARCH_AUTO_RETRIES {
+                gloo_timers::future::TimeoutFuture::new(SEARCH_RETRY_MS).await;
+                if SHOW_SEARCH() {
+                    attempts += 1;
+                }
             }
         }
     });
 
     // when we search, we do a similar search to mdbook
     // This will bring up individual sections that reference the search term with the breadcrumb
-    // entries are sorted by breadcrumb
+    // entries are grouped by the top-level section of their breadcrumb
 
     rsx! {
         div {
@@ -212,8 +266,31 @@ fn SearchModal() -> Element {
                                 input {
                                     onclick: move |evt| evt.stop_propagation(),
                                     onkeydown: move |evt| {
-                                        if evt.key() == Key::Escape {
-                                            *SHOW_SEARCH.write() = false;
+                                        let count = groups.read().iter().map(|group| group.results.len()).sum::<usize>();
+                                        match evt.key() {
+                                            Key::Escape => *SHOW_SEARCH.write() = false,
+                                            Key::ArrowDown if count > 0 => {
+                                                evt.prevent_default();
+                                                selected.set((selected() + 1) % count);
+                                                scroll_to_result(selected());
+                                            }
+                                            Key::ArrowUp if count > 0 => {
+                                                evt.prevent_default();
+                                                selected.set((selected() + count - 1) % count);
+                                                scroll_to_result(selected());
+                                            }
+                                            Key::Enter => {
+                                                let groups = groups.read();
+                                                let result = groups
+                                                    .iter()
+                                                    .flat_map(|group| group.results.iter())
+                                                    .nth(selected());
+                                                if let Some(result) = result {
+                                                    navigator().push(result_url(result));
+                                                    *SHOW_SEARCH.write() = false;
+                                                }
+                                            }
+                                            _ => {}
                                         }
                                     },
                                     oninput: move |evt| {
@@ -230,9 +307,98 @@ fn SearchModal() -> Element {
                         }
                     }
 
+                    SearchFilterChips { filters, selected }
+
                     // Results
                     div { class: "overflow-y-auto",
-                        ul { SearchResults { results, search_text } }
+                        SearchResults {
+                            results,
+                            groups,
+                            selected,
+                            search_text,
+                            retrying: attempts() < SEARCH_AUTO_RETRIES,
+                            on_retry: move |_| attempts.set(0)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Chips to narrow the results down to a kind of page, and for the docs a version and language
+#[component]
+fn SearchFilterChips(
+    mut filters: Signal<search::SearchFilters>,
+    mut selected: Signal<usize>,
+) -> Element {
+    let current = filters();
+
+    rsx! {
+        div {
+            class: "flex flex-row flex-wrap gap-2 pt-4",
+            onclick: move |evt| evt.stop_propagation(),
+            FilterChip {
+                label: "All",
+                active: current.kind.is_none(),
+                onclick: move |_| {
+                    filters.write().kind = None;
+                    selected.set(0);
+                }
+            }
+            for kind in search::RouteKind::FILTERABLE {
+                FilterChip {
+                    label: kind.name(),
+                    active: current.kind == Some(kind),
+                    onclick: move |_| {
+                        let mut filters = filters.write();
+                        filters.kind = Some(kind);
+                        // A blog post doesn't have a docs version, so the docs filters would hide everything
+                        if !filters.docs_facets_apply() {
+                            filters.version = None;
+                            filters.language = None;
+                        }
+                        selected.set(0);
+                    }
+                }
+            }
+        }
+        if current.docs_facets_apply() {
+            div {
+                class: "flex flex-row flex-wrap gap-2 pt-2",
+                onclick: move |evt| evt.stop_propagation(),
+                // A filter for a single version or language wouldn't narrow anything down
+                if search::INDEXED_FACETS.versions.len() > 1 {
+                    for version in search::INDEXED_FACETS.versions.iter().copied() {
+                        FilterChip {
+                            label: version,
+                            active: current.version == Some(version),
+                            onclick: move |_| {
+                                let mut filters = filters.write();
+                                filters.version = if filters.version == Some(version) { None } else { Some(version) };
+                                selected.set(0);
+                            }
+                        }
+                    }
+                }
+                if search::INDEXED_FACETS.languages.len() > 1 {
+                    for language in crate::docs::router_05::LANGUAGES
+                        .iter()
+                        .filter(|language| search::INDEXED_FACETS.languages.contains(&language.code))
+                    {
+                        FilterChip {
+                            label: language.name,
+                            active: current.language == Some(language.code),
+                            onclick: move |_| {
+                                let mut filters = filters.write();
+                                filters.language = if filters.language == Some(language.code) {
+                                    None
+                                } else {
+                                    Some(language.code)
+                                };
+                                selected.set(0);
+                            }
+                        }
                     }
                 }
             }
@@ -241,18 +407,143 @@ fn SearchModal() -> Element {
 }
 
 #[component]
-fn SearchResults(results: Signal<Results>, search_text: Signal<String>) -> Element {
-    if let Err(err) = results.read().as_ref() {
-        return rsx! { div { class: "text-red-500", "{err}" } };
+pub(crate) fn FilterChip(label: &'static str, active: bool, onclick: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        button {
+            class: "rounded-full border px-3 py-1 text-xs",
+            class: if active { "border-sky-500 bg-sky-500 text-white" } else { "border-gray-300 dark:border-gray-700 hover:border-sky-500" },
+            onclick: move |evt| onclick.call(evt),
+            "{label}"
+        }
     }
+}
+
+/// Group results by the top-level section they belong to, keeping the groups in the order their best
+/// result ranked
+fn group_results(results: &[dioxus_search::SearchResult<Route>]) -> Vec<SearchGroup> {
+    let mut groups: Vec<SearchGroup> = Vec::new();
+    for result in results {
+        let name = result_section(&result.route);
+        match groups.iter_mut().find(|group| group.name == name) {
+            Some(group) => group.results.push(result.clone()),
+            None => groups.push(SearchGroup {
+                name,
+                results: vec![result.clone()],
+            }),
+        }
+    }
+    groups
+}
+
+/// The name of the top-level section of the site a route is in
+fn result_section(route: &Route) -> &'static str {
+    match route {
+        Route::Docs { .. } | Route::DocsTranslated { .. } => breadcrumb(book_page(route))
+            .first()
+            .copied()
+            .unwrap_or("Docs"),
+        Route::Blog { .. } => "Blog",
+        Route::Awesome { .. } | Route::AwesomeItem { .. } => "Awesome",
+        _ => "Dioxus",
+    }
+}
 
-    let _results = results.read();
-    let results = _results.deref().as_ref().unwrap();
+/// The heading of the page the matched text is most likely under.
+///
+/// The index doesn't record which heading an excerpt came from, so we pick the first heading of the
+/// page that contains one of the highlighted words.
+fn result_anchor(result: &dioxus_search::SearchResult<Route>) -> Option<String> {
+    if !matches!(result.route, Route::Docs { .. } | Route::DocsTranslated { .. }) {
+        return None;
+    }
+    let terms: Vec<String> = result
+        .excerpts
+        .iter()
+        .flat_map(|excerpt| excerpt.text.iter())
+        .filter(|segment| segment.highlighted)
+        .map(|segment| segment.text.trim().to_lowercase())
+        .filter(|term| !term.is_empty())
+        .collect();
+
+    // The first heading is the title of the page
+    page_headings(&result.route)
+        .iter()
+        .skip(1)
+        .find(|heading| {
+            let title = heading.title.to_lowercase();
+            terms.iter().any(|term| title.contains(term))
+        })
+        .map(|heading| heading.id.to_string())
+}
+
+/// The url a result links to, with the heading of the match in the fragment. The scroll spy of the
+/// docs scrolls to it once the page has rendered.
+fn result_url(result: &dioxus_search::SearchResult<Route>) -> String {
+    match result_anchor(result) {
+        Some(anchor) => format!("{}#{anchor}", result.route),
+        None => result.route.to_string(),
+    }
+}
+
+/// Keep the result selected with the arrow keys in view
+fn scroll_to_result(index: usize) {
+    #[cfg(feature = "web")]
+    {
+        let element = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id(&format!("search-result-{index}")));
+        if let Some(element) = element {
+            element.scroll_into_view_with_bool(false);
+        }
+    }
+}
 
-    if !results.is_empty() {
+#[component]
+fn SearchResults(
+    results: Signal<Results>,
+    groups: Memo<Vec<SearchGroup>>,
+    selected: Signal<usize>,
+    search_text: Signal<String>,
+    retrying: bool,
+    on_retry: EventHandler,
+) -> Element {
+    if results.read().is_err() {
+        return rsx! {
+            div { class: "text-center text-xlg p-4",
+                if retrying {
+                    "The search index is still loading..."
+                } else {
+                    "The search index couldn't be loaded."
+                    div { class: "mt-4",
+                        button {
+                            class: "underline p-1 md:p-2",
+                            onclick: move |evt| {
+                                evt.stop_propagation();
+                                on_retry.call(());
+                            },
+                            "Retry"
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    if !groups.read().is_empty() {
+        let mut index = 0;
         return rsx! {
-            for result in results {
-                SearchResult { result: result.clone() }
+            for group in groups() {
+                div { class: "mt-6",
+                    h3 { class: "text-xs font-semibold uppercase tracking-wide text-gray-500 dark:text-gray-400 px-2",
+                        "{group.name}"
+                    }
+                    ul {
+                        for result in group.results {
+                            SearchResult { result, index, selected }
+                            {index += 1}
+                        }
+                    }
+                }
             }
         };
     }
@@ -264,12 +555,13 @@ fn SearchResults(results: Signal<Results>, search_text: Signal<String>) -> Eleme
                 div {
                     "Try searching for:"
                     ul {
-                        for search in ["Fullstack", "Typesafe Routing", "Authentication"] {
+                        for search in search_log::SUGGESTIONS() {
                             li {
                                 button {
                                     class: "underline p-1 md:p-2",
-                                    onclick: move |_| {
-                                        search_text.set(search.to_string());
+                                    onclick: {
+                                        let search = search.clone();
+                                        move |_| search_text.set(search.clone())
                                     },
                                     "{search}"
                                 }
@@ -278,6 +570,17 @@ fn SearchResults(results: Signal<Results>, search_text: Signal<String>) -> Eleme
                     }
                 }
 
+                label {
+                    class: "mt-4 flex flex-row items-center gap-2 text-sm text-gray-500 dark:text-gray-400",
+                    onclick: move |evt| evt.stop_propagation(),
+                    input {
+                        r#type: "checkbox",
+                        checked: search_log::RECORD_SEARCH_MISSES(),
+                        oninput: move |evt| search_log::set_record_search_misses(evt.checked())
+                    }
+                    "Help improve the docs by sharing searches that find nothing"
+                }
+
                 div { class: "mt-4",
                     "Or go to:"
                     ul {
@@ -294,27 +597,49 @@ fn SearchResults(results: Signal<Results>, search_text: Signal<String>) -> Eleme
 }
 
 #[component]
-fn SearchResult(result: dioxus_search::SearchResult<Route>) -> Element {
+fn SearchResult(
+    result: dioxus_search::SearchResult<Route>,
+    index: usize,
+    mut selected: Signal<usize>,
+) -> Element {
     let title = &result.title;
     let route = &result.route;
-    let top_excerpt_segments = &result.excerpts.first().unwrap().text;
+    let url = result_url(&result);
+    // Everything above the page itself, the top-level section is already the name of the group
+    let trail = match route {
+        Route::Docs { .. } | Route::DocsTranslated { .. } => {
+            let trail = breadcrumb(book_page(route));
+            let end = trail.len().saturating_sub(1);
+            trail[1.min(end)..end].join(" › ")
+        }
+        _ => String::new(),
+    };
 
     rsx! {
-        li { class: "w-full mt-4 p-2 rounded hover:bg-gray-100 dark:hover:bg-ideblack transition-colors duration-200 ease-in-out",
+        li {
+            id: "search-result-{index}",
+            class: "w-full mt-4 p-2 rounded hover:bg-gray-100 dark:hover:bg-ideblack transition-colors duration-200 ease-in-out",
+            class: if selected() == index { "bg-gray-100 dark:bg-gray-800" },
+            onmouseenter: move |_| selected.set(index),
             Link {
-                to: route.clone(),
+                to: url,
                 onclick: move |_| {
                     *SHOW_SEARCH.write() = false;
                 },
                 div { class: "flex flex-col justify-between pb-1",
+                    if !trail.is_empty() {
+                        span { class: "text-xs text-gray-500 dark:text-gray-400", "{trail}" }
+                    }
                     h2 { class: "font-semibold dark:text-white", "{title}" }
                 }
-                p { class: "text-sm pr-8 text-gray-500 dark:text-gray-300",
-                    for segment in top_excerpt_segments {
-                        if segment.highlighted {
-                            span { class: "text-blue-500", "{segment.text}" }
-                        } else {
-                            span { "{segment.text}" }
+                for excerpt in result.excerpts.iter() {
+                    p { class: "text-sm pr-8 pb-1 text-gray-500 dark:text-gray-300",
+                        for segment in excerpt.text.iter() {
+                            if segment.highlighted {
+                                span { class: "text-blue-500", "{segment.text}" }
+                            } else {
+                                span { "{segment.text}" }
+                            }
                         }
                     }
                 }
@@ -0,0 +1,291 @@
+//! Renders every example the docs show in a `DemoFrame` with dioxus-ssr and compares the html with a
+//! snapshot, so an example that breaks when dioxus is updated fails the tests instead of the page.
+//!
+//! Run with `cargo test --features doc_test`. The examples are the ones `build/includes.rs` finds in
+//! the `inject-dioxus` blocks of the docs. Snapshots live in `src/doc_examples/snapshots`, one file
+//! per example with the html after the first render and after each event sent to it. A missing or
+//! changed snapshot fails the run; set `UPDATE_SNAPSHOTS=1` to write the snapshots that changed on
+//! purpose.
+
+use crate::doc_examples::*;
+use crate::docs::DemoFrame;
+use dioxus::dioxus_core::{ElementId, Mutation, Mutations};
+use dioxus::html::{
+    set_event_converter, PlatformEventData, SerializedFormData, SerializedHtmlEventConverter,
+    SerializedMouseData,
+};
+use dioxus::prelude::*;
+use futures::FutureExt;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+include!(concat!(env!("OUT_DIR"), "/injected_examples.rs"));
+
+const SNAPSHOT_DIR: &str = "src/doc_examples/snapshots";
+
+/// An event sent to the nth element of the example that listens for it
+#[derive(Clone, Copy, Debug)]
+enum Event {
+    Click(usize),
+    Input(usize, &'static str),
+    Submit(usize),
+}
+
+/// The events sent to an example after its first render
+const EVENTS: &[(&str, &[Event])] = &[
+    (
+        "conditional_rendering::App",
+        &[Event::Click(0), Event::Click(0)],
+    ),
+    (
+        "conditional_rendering::LogInImprovedApp",
+        &[Event::Click(0), Event::Click(0)],
+    ),
+    ("event_click::App", &[Event::Click(0)]),
+    ("event_prevent_default::App", &[Event::Click(0)]),
+    (
+        "hooks_counter::App",
+        &[Event::Click(0), Event::Click(0), Event::Click(1)],
+    ),
+    (
+        "hooks_counter_two_state::App",
+        &[Event::Click(0), Event::Click(2), Event::Click(3)],
+    ),
+    ("hooks_use_signal::App", &[Event::Click(0), Event::Click(0)]),
+    ("input_controlled::App", &[Event::Input(0, "alice")]),
+    ("input_uncontrolled::App", &[Event::Submit(0)]),
+    (
+        "readme::App",
+        &[Event::Click(0), Event::Click(1), Event::Click(1)],
+    ),
+    (
+        "rendering_lists::App",
+        &[
+            Event::Input(0, "first"),
+            Event::Submit(0),
+            Event::Input(0, "second"),
+            Event::Submit(0),
+        ],
+    ),
+    (
+        "rendering_lists::AppForLoop",
+        &[Event::Input(0, "first"), Event::Submit(0)],
+    ),
+];
+
+/// Examples that fetch from the network in a task. Their tasks are never polled, so the snapshot is the
+/// loading state of the first render and the tests don't depend on the APIs they call.
+const FETCHING: &[&str] = &[
+    "hackernews_async::App",
+    "hackernews_async::fetch::App",
+    "hackernews_complete::App",
+    "use_resource::App",
+];
+
+struct Example {
+    path: &'static str,
+    app: fn() -> Element,
+    events: &'static [Event],
+    fetches: bool,
+}
+
+fn examples() -> impl Iterator<Item = Example> {
+    INJECTED_EXAMPLES.iter().map(|&(path, app)| Example {
+        path,
+        app,
+        events: EVENTS
+            .iter()
+            .find(|(example, _)| *example == path)
+            .map_or(&[], |(_, events)| events),
+        fetches: FETCHING.contains(&path),
+    })
+}
+
+#[test]
+fn listed_examples_are_injected() {
+    let listed = EVENTS.iter().map(|(path, _)| path).chain(FETCHING);
+    let stale: Vec<_> = listed
+        .filter(|path| {
+            !INJECTED_EXAMPLES
+                .iter()
+                .any(|(injected, _)| injected == *path)
+        })
+        .collect();
+    assert!(stale.is_empty(), "examples that no page injects: {stale:?}");
+}
+
+// The examples are rendered in a runtime for the tasks of those that spawn some
+#[tokio::test]
+async fn examples_match_snapshots() {
+    set_event_converter(Box::new(SerializedHtmlEventConverter));
+
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut failures = Vec::new();
+
+    for example in examples() {
+        let rendered = match render_example(&example) {
+            Ok(rendered) => rendered,
+            Err(err) => {
+                failures.push(format!("{}: {err}", example.path));
+                continue;
+            }
+        };
+
+        let path = snapshot_path(example.path);
+        match std::fs::read_to_string(&path) {
+            Ok(snapshot) if snapshot == rendered => {}
+            _ if update => {
+                std::fs::create_dir_all(SNAPSHOT_DIR).unwrap();
+                std::fs::write(&path, &rendered).unwrap();
+                println!("wrote {}", path.display());
+            }
+            Ok(snapshot) => {
+                println!(
+                    "{} changed:\n{}",
+                    example.path,
+                    pretty_assertions::StrComparison::new(&snapshot, &rendered)
+                );
+                failures.push(format!(
+                    "{}: the html doesn't match the snapshot",
+                    example.path
+                ));
+            }
+            Err(_) => {
+                failures.push(format!(
+                    "{}: no snapshot at {}",
+                    example.path,
+                    path.display()
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} examples failed, rerun with UPDATE_SNAPSHOTS=1 if the changes are expected:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+/// `hackernews_async::fetch::App` is snapshotted in `hackernews_async-fetch-App.html`
+fn snapshot_path(example: &str) -> PathBuf {
+    Path::new(SNAPSHOT_DIR).join(format!("{}.html", example.replace("::", "-")))
+}
+
+/// The html after the first render followed by the html after each event
+fn render_example(example: &Example) -> Result<String, String> {
+    if example.fetches && !example.events.is_empty() {
+        return Err("sending events would run the tasks that fetch from the network".to_string());
+    }
+    let mut dom = ExampleDom::new(example.app, !example.fetches);
+    let mut snapshot = format!("{}\n", dom.html());
+    for event in example.events {
+        dom.send(*event)?;
+        snapshot.push_str(&format!("<!-- {event:?} -->\n{}\n", dom.html()));
+    }
+    Ok(snapshot)
+}
+
+#[component]
+fn Frame(app: fn() -> Element) -> Element {
+    let App = app;
+    rsx! {
+        DemoFrame { App {} }
+    }
+}
+
+/// A virtual dom that keeps track of the elements with listeners so tests can send events to them
+struct ExampleDom {
+    dom: VirtualDom,
+    /// Listeners in the order they were added, which is the order of the elements on the first render
+    listeners: Vec<(String, ElementId)>,
+}
+
+impl ExampleDom {
+    /// Render the first frame, and everything the tasks of the app change if `run_tasks` is set
+    fn new(app: fn() -> Element, run_tasks: bool) -> Self {
+        let mut dom = Self {
+            dom: VirtualDom::new_with_props(Frame, FrameProps { app }),
+            listeners: Vec::new(),
+        };
+        let mut mutations = Mutations::default();
+        dom.dom.rebuild(&mut mutations);
+        dom.apply(mutations);
+        if run_tasks {
+            dom.settle();
+        }
+        dom
+    }
+
+    fn html(&self) -> String {
+        dioxus_ssr::render(&self.dom)
+    }
+
+    fn send(&mut self, event: Event) -> Result<(), String> {
+        let (name, index, data) = match event {
+            Event::Click(index) => (
+                "click",
+                index,
+                PlatformEventData::new(Box::<SerializedMouseData>::default()),
+            ),
+            Event::Input(index, value) => (
+                "input",
+                index,
+                PlatformEventData::new(Box::new(SerializedFormData::new(
+                    value.to_string(),
+                    HashMap::new(),
+                ))),
+            ),
+            Event::Submit(index) => (
+                "submit",
+                index,
+                PlatformEventData::new(Box::<SerializedFormData>::default()),
+            ),
+        };
+        let Some(id) = self
+            .listeners
+            .iter()
+            .filter(|(listener, _)| listener == name)
+            .map(|(_, id)| *id)
+            .nth(index)
+        else {
+            return Err(format!(
+                "{event:?}: there is no element number {index} listening for {name}"
+            ));
+        };
+
+        self.dom.handle_event(name, Rc::new(data), id, true);
+        self.settle();
+        Ok(())
+    }
+
+    /// Rerender until nothing is dirty. Tasks that are still waiting, like requests, are left alone.
+    fn settle(&mut self) {
+        while self.dom.wait_for_work().now_or_never().is_some() {
+            let mutations = self.dom.render_immediate_to_vec();
+            self.apply(mutations);
+        }
+    }
+
+    fn apply(&mut self, mutations: Mutations) {
+        for edit in mutations.edits {
+            match edit {
+                Mutation::NewEventListener { name, id } => {
+                    if !self.listeners.contains(&(name.clone(), id)) {
+                        self.listeners.push((name, id));
+                    }
+                }
+                Mutation::RemoveEventListener { name, id } => {
+                    self.listeners
+                        .retain(|listener| listener != &(name.clone(), id));
+                }
+                Mutation::Remove { id } | Mutation::ReplaceWith { id, .. } => {
+                    self.listeners.retain(|(_, listener)| *listener != id);
+                }
+                _ => {}
+            }
+        }
+    }
+}
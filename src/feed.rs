@@ -0,0 +1,98 @@
+//! Generates the Atom and RSS feeds for the blog from [`POSTS`].
+
+use crate::sitemap::{escape_xml, SITE_URL};
+use crate::*;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use std::fmt::Write;
+use std::path::Path;
+
+const FEED_TITLE: &str = "Dioxus Blog";
+const FEED_DESCRIPTION: &str = "Updates, changelogs, and general musings of the Dioxus community";
+const FEED_AUTHOR: &str = "Dioxus Labs";
+
+/// Write `blog/feed.xml` (Atom) and `blog/rss.xml` into the given output directory
+pub(crate) fn generate(out_dir: &Path) -> std::io::Result<()> {
+    let blog_dir = out_dir.join("blog");
+    std::fs::create_dir_all(&blog_dir)?;
+    std::fs::write(out_dir.join(ATOM_FEED.trim_start_matches('/')), atom_feed())?;
+    std::fs::write(out_dir.join(RSS_FEED.trim_start_matches('/')), rss_feed())?;
+    Ok(())
+}
+
+/// Posts don't have a publishing time, so we pretend they all went out at midnight UTC
+fn published(post: &BlogPost) -> DateTime<Utc> {
+    let (year, month, day) = post.published;
+    NaiveDate::from_ymd_opt(year, month, day)
+        .unwrap_or_default()
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+}
+
+fn post_url(post: &BlogPost) -> String {
+    format!("{SITE_URL}{}", post.link)
+}
+
+fn atom_feed() -> String {
+    let updated = POSTS.iter().map(published).max().unwrap_or_default();
+
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    xml.push('\n');
+    _ = writeln!(xml, r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+    _ = writeln!(xml, "  <title>{FEED_TITLE}</title>");
+    _ = writeln!(xml, "  <subtitle>{FEED_DESCRIPTION}</subtitle>");
+    _ = writeln!(xml, "  <id>{SITE_URL}/blog/</id>");
+    _ = writeln!(xml, r#"  <link href="{SITE_URL}/blog/"/>"#);
+    _ = writeln!(xml, r#"  <link rel="self" href="{SITE_URL}{ATOM_FEED}"/>"#);
+    _ = writeln!(xml, "  <updated>{}</updated>", updated.to_rfc3339());
+    _ = writeln!(xml, "  <author><name>{FEED_AUTHOR}</name></author>");
+
+    for post in POSTS {
+        let url = escape_xml(&post_url(post));
+        _ = writeln!(xml, "  <entry>");
+        _ = writeln!(xml, "    <title>{}</title>", escape_xml(post.title));
+        _ = writeln!(xml, "    <id>{url}</id>");
+        _ = writeln!(xml, r#"    <link href="{url}"/>"#);
+        _ = writeln!(xml, "    <published>{}</published>", published(post).to_rfc3339());
+        _ = writeln!(xml, "    <updated>{}</updated>", published(post).to_rfc3339());
+        _ = writeln!(xml, r#"    <category term="{}"/>"#, escape_xml(post.category));
+        _ = writeln!(xml, "    <summary>{}</summary>", escape_xml(post.description));
+        _ = writeln!(xml, r#"    <content type="html">{}</content>"#, escape_xml(post.content));
+        _ = writeln!(xml, "  </entry>");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+fn rss_feed() -> String {
+    let updated = POSTS.iter().map(published).max().unwrap_or_default();
+
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    xml.push('\n');
+    _ = writeln!(xml, r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">"#);
+    _ = writeln!(xml, "  <channel>");
+    _ = writeln!(xml, "    <title>{FEED_TITLE}</title>");
+    _ = writeln!(xml, "    <link>{SITE_URL}/blog/</link>");
+    _ = writeln!(xml, "    <description>{FEED_DESCRIPTION}</description>");
+    _ = writeln!(xml, "    <language>en</language>");
+    _ = writeln!(xml, "    <lastBuildDate>{}</lastBuildDate>", updated.to_rfc2822());
+    _ = writeln!(
+        xml,
+        r#"    <atom:link href="{SITE_URL}{RSS_FEED}" rel="self" type="application/rss+xml"/>"#
+    );
+
+    for post in POSTS {
+        let url = escape_xml(&post_url(post));
+        _ = writeln!(xml, "    <item>");
+        _ = writeln!(xml, "      <title>{}</title>", escape_xml(post.title));
+        _ = writeln!(xml, "      <link>{url}</link>");
+        _ = writeln!(xml, r#"      <guid isPermaLink="true">{url}</guid>"#);
+        _ = writeln!(xml, "      <pubDate>{}</pubDate>", published(post).to_rfc2822());
+        _ = writeln!(xml, "      <category>{}</category>", escape_xml(post.category));
+        _ = writeln!(xml, "      <description>{}</description>", escape_xml(post.description));
+        _ = writeln!(xml, "    </item>");
+    }
+
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
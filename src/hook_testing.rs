@@ -0,0 +1,243 @@
+//! Test hooks by driving a virtual dom by hand.
+//!
+//! [`HookTester`] mounts a hook in a component that renders nothing else. Tests can rerun it, give it
+//! the context it reads, let the tasks it spawns run, move time forward and check the signals it
+//! returns. The testing page of the cookbook includes the tests at the bottom of this file.
+//!
+//! Time is tokio's paused clock, so tests that use [`HookTester::advance`] have to run with
+//! `#[tokio::test(start_paused = true)]`. Nothing sleeps for real and timers fire in the same order
+//! on every run.
+
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::prelude::*;
+use futures::FutureExt;
+use std::cell::{Cell, RefCell};
+use std::fmt::Debug;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How many renders in a row [`HookTester::run_until_idle`] allows before it decides the hook
+/// never settles
+const MAX_RENDERS: usize = 1000;
+
+// ANCHOR: harness
+/// A hook mounted in an otherwise empty component
+pub(crate) struct HookTester<V> {
+    dom: VirtualDom,
+    value: Rc<RefCell<Option<V>>>,
+    generation: Rc<Cell<usize>>,
+    rerun: Rc<RefCell<Option<Arc<dyn Fn() + Send + Sync>>>>,
+}
+
+impl<V: Clone + 'static> HookTester<V> {
+    /// Mount the hook and render it once
+    pub(crate) fn new(hook: impl FnMut() -> V + 'static) -> Self {
+        Self::with_contexts(hook, |_| {})
+    }
+
+    /// Mount the hook with contexts provided at the root, for hooks that call `use_context`
+    pub(crate) fn with_contexts(
+        mut hook: impl FnMut() -> V + 'static,
+        provide: impl FnOnce(&VirtualDom),
+    ) -> Self {
+        let value = Rc::new(RefCell::new(None));
+        let generation = Rc::new(Cell::new(0));
+        let rerun = Rc::new(RefCell::new(None));
+
+        let run_hook = {
+            let value = value.clone();
+            let generation = generation.clone();
+            let rerun = rerun.clone();
+            move || {
+                *value.borrow_mut() = Some(hook());
+                generation.set(dioxus::prelude::generation());
+                rerun.borrow_mut().get_or_insert_with(schedule_update);
+            }
+        };
+
+        let dom = VirtualDom::new_with_props(
+            MockApp,
+            MockAppProps {
+                run_hook: Rc::new(RefCell::new(run_hook)),
+            },
+        );
+        provide(&dom);
+
+        let mut tester = Self {
+            dom,
+            value,
+            generation,
+            rerun,
+        };
+        tester.dom.rebuild_in_place();
+        tester.render_dirty();
+        tester
+    }
+
+    /// What the hook returned the last time it ran
+    pub(crate) fn value(&self) -> V {
+        self.value.borrow().clone().expect("the hook has run")
+    }
+
+    /// How many times the hook has rerun since it was mounted
+    pub(crate) fn generation(&self) -> usize {
+        self.generation.get()
+    }
+
+    /// Rerun the hook as if its component was marked dirty
+    pub(crate) fn rerun(&mut self) {
+        let rerun = self.rerun.borrow().clone().expect("the hook has run");
+        rerun();
+        self.render_dirty();
+    }
+
+    /// Render until nothing is dirty, without waiting on tasks that are still pending
+    pub(crate) fn render_dirty(&mut self) {
+        for _ in 0..MAX_RENDERS {
+            if self.dom.wait_for_work().now_or_never().is_none() {
+                return;
+            }
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+        panic!("the hook kept rerendering after {MAX_RENDERS} renders");
+    }
+
+    /// Let tasks spawned by the hook (and tokio tasks they wait on) run until all of them are
+    /// waiting on something that isn't ready
+    pub(crate) async fn run_until_idle(&mut self) {
+        for _ in 0..MAX_RENDERS {
+            tokio::task::yield_now().await;
+            if self.dom.wait_for_work().now_or_never().is_none() {
+                return;
+            }
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+        panic!("the hook never became idle after {MAX_RENDERS} renders");
+    }
+
+    /// Move the virtual clock forward, firing every timer that expires on the way
+    pub(crate) async fn advance(&mut self, duration: Duration) {
+        tokio::time::advance(duration).await;
+        self.run_until_idle().await;
+    }
+
+    /// Run a closure in the scope of the hook, for reading signals and calling hooks' methods
+    pub(crate) fn in_runtime<O>(&self, f: impl FnOnce() -> O) -> O {
+        self.dom.in_runtime(|| ScopeId::ROOT.in_runtime(f))
+    }
+
+    /// Assert that a signal (or memo, or anything else readable) holds the expected value
+    #[track_caller]
+    pub(crate) fn assert_signal<T: PartialEq + Debug + 'static>(
+        &self,
+        signal: impl Readable<Target = T>,
+        expected: T,
+    ) {
+        let matches = self.in_runtime(|| {
+            let actual = signal.peek();
+            (*actual == expected).then_some(()).ok_or_else(|| format!("{:?}", &*actual))
+        });
+        if let Err(actual) = matches {
+            panic!("expected the signal to be {expected:?}, but it is {actual}");
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct MockAppProps {
+    run_hook: Rc<RefCell<dyn FnMut()>>,
+}
+
+impl PartialEq for MockAppProps {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+fn MockApp(props: MockAppProps) -> Element {
+    (props.run_hook.borrow_mut())();
+    rsx! { div {} }
+}
+// ANCHOR_END: harness
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ANCHOR: signal
+    #[test]
+    fn counter() {
+        let mut tester = HookTester::new(|| use_signal(|| 0));
+        assert_eq!(tester.generation(), 0);
+
+        let mut count = tester.value();
+        tester.in_runtime(|| count += 1);
+        tester.assert_signal(count, 1);
+
+        tester.rerun();
+        assert_eq!(tester.generation(), 1);
+        tester.assert_signal(tester.value(), 1);
+    }
+    // ANCHOR_END: signal
+
+    // ANCHOR: context
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum Theme {
+        Light,
+        Dark,
+    }
+
+    fn use_theme() -> Theme {
+        use_context::<Theme>()
+    }
+
+    #[test]
+    fn reads_context() {
+        let tester = HookTester::with_contexts(use_theme, |dom| dom.provide_root_context(Theme::Dark));
+        assert_eq!(tester.value(), Theme::Dark);
+    }
+    // ANCHOR_END: context
+
+    // ANCHOR: resource
+    #[tokio::test(start_paused = true)]
+    async fn resource_waits_for_the_clock() {
+        let mut tester = HookTester::new(|| {
+            use_resource(|| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                "loaded"
+            })
+        });
+        tester.run_until_idle().await;
+        assert_eq!(tester.in_runtime(|| tester.value().cloned()), None);
+
+        tester.advance(Duration::from_secs(4)).await;
+        assert_eq!(tester.in_runtime(|| tester.value().cloned()), None);
+
+        tester.advance(Duration::from_secs(1)).await;
+        assert_eq!(tester.in_runtime(|| tester.value().cloned()), Some("loaded"));
+    }
+    // ANCHOR_END: resource
+
+    // ANCHOR: coroutine
+    #[tokio::test(start_paused = true)]
+    async fn coroutine_handles_messages() {
+        let mut tester = HookTester::new(|| {
+            let mut total = use_signal(|| 0);
+            let adder = use_coroutine(|mut rx: UnboundedReceiver<i32>| async move {
+                use futures::StreamExt;
+                while let Some(amount) = rx.next().await {
+                    total += amount;
+                }
+            });
+            (total, adder)
+        });
+
+        let (total, adder) = tester.value();
+        adder.send(2);
+        adder.send(3);
+        tester.run_until_idle().await;
+        tester.assert_signal(total, 5);
+    }
+    // ANCHOR_END: coroutine
+}
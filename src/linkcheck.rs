@@ -0,0 +1,278 @@
+//! Checks the links of the prebuilt site in `docs/`.
+//!
+//! Run with `cargo run --features prebuild -- linkcheck` after prebuilding. Every generated
+//! `index.html` is scanned for `href`s. Internal links have to parse into a [`Route`] that isn't the
+//! 404 page (or point at a file that exists), and their `#fragment` has to be an id on the target page.
+//! External links are only checked with `--external`, so the check works offline.
+
+use crate::sitemap::SITE_URL;
+use crate::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Check every page in the output directory. Returns false and prints a report if any link is broken.
+pub(crate) async fn run(out_dir: &Path, check_external: bool) -> bool {
+    let mut pages = Vec::new();
+    collect_pages(out_dir, out_dir, &mut pages);
+    pages.sort();
+
+    let mut ids: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut hrefs: Vec<(String, Vec<String>)> = Vec::new();
+    for (url, path) in &pages {
+        let html = std::fs::read_to_string(path).unwrap();
+        ids.insert(url.clone(), attribute_values(&html, "id").into_iter().collect());
+        hrefs.push((url.clone(), attribute_values(&html, "href")));
+    }
+
+    let mut broken: BTreeMap<&str, Vec<(String, String)>> = BTreeMap::new();
+    let mut external: HashMap<String, Vec<&str>> = HashMap::new();
+    for (page, links) in &hrefs {
+        for href in links {
+            match Href::parse(page, href) {
+                Href::Skip => {}
+                Href::External(url) => external.entry(url).or_default().push(page),
+                Href::Internal { path, fragment } => {
+                    if let Err(reason) = check_internal(out_dir, &ids, &path, fragment.as_deref()) {
+                        broken.entry(page).or_default().push((href.clone(), reason));
+                    }
+                }
+            }
+        }
+    }
+
+    if check_external {
+        let client = reqwest::Client::new();
+        for (url, sources) in &external {
+            if let Err(reason) = check_external_link(&client, url).await {
+                for page in sources {
+                    broken.entry(page).or_default().push((url.clone(), reason.clone()));
+                }
+            }
+        }
+    }
+
+    println!(
+        "checked {} pages, {} external links {}",
+        pages.len(),
+        external.len(),
+        if check_external { "checked" } else { "skipped" }
+    );
+
+    if broken.is_empty() {
+        return true;
+    }
+
+    let count: usize = broken.values().map(Vec::len).sum();
+    println!("found {count} broken links:");
+    for (page, links) in &broken {
+        println!("\n{page}");
+        for (href, reason) in links {
+            println!("  {href}: {reason}");
+        }
+    }
+    false
+}
+
+#[derive(PartialEq, Debug)]
+enum Href {
+    /// `mailto:`, `javascript:` and friends
+    Skip,
+    External(String),
+    Internal {
+        path: String,
+        fragment: Option<String>,
+    },
+}
+
+impl Href {
+    /// Classify a `href` found on the page at `page`, resolving relative paths like a browser does:
+    /// against the folder of the page, which is the page itself only if its url ends in `/`
+    fn parse(page: &str, href: &str) -> Self {
+        let href = href.trim();
+        if href.is_empty()
+            || ["mailto:", "javascript:", "data:", "tel:"]
+                .iter()
+                .any(|scheme| href.starts_with(scheme))
+        {
+            return Href::Skip;
+        }
+
+        let href = href.strip_prefix(SITE_URL).unwrap_or(href);
+        if href.starts_with("http://") || href.starts_with("https://") || href.starts_with("//") {
+            return Href::External(href.to_string());
+        }
+
+        let (href, fragment) = match href.split_once('#') {
+            Some((href, fragment)) => (href, Some(fragment.to_string())),
+            None => (href, None),
+        };
+        let href = href.split('?').next().unwrap_or_default();
+
+        let path = if href.is_empty() {
+            page.to_string()
+        } else if href.starts_with('/') {
+            normalize_path(href)
+        } else {
+            let folder = page.rsplit_once('/').map_or("", |(folder, _)| folder);
+            normalize_path(&format!("{folder}/{href}"))
+        };
+
+        Href::Internal { path, fragment }
+    }
+}
+
+/// Resolve `.` and `..` segments and drop empty ones, so every path looks like `/a/b`
+fn normalize_path(path: &str) -> String {
+    let mut segments = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+fn check_internal(
+    out_dir: &Path,
+    ids: &HashMap<String, HashSet<String>>,
+    path: &str,
+    fragment: Option<&str>,
+) -> Result<(), String> {
+    // Stylesheets, images, feeds and everything else that isn't a page
+    let file = out_dir.join(path.trim_start_matches('/'));
+    if file.is_file() {
+        return Ok(());
+    }
+
+    let route = match path.parse::<Route>() {
+        Ok(Route::Err404 { .. }) | Err(_) => return Err("no route matches this path".to_string()),
+        Ok(route) => route,
+    };
+
+    let Some(fragment) = fragment.filter(|fragment| !fragment.is_empty()) else {
+        return Ok(());
+    };
+
+    // Redirects parse into the route they point to, so look the ids up by the route, not the path
+    let page_ids = ids.get(&normalize_path(&route.to_string()));
+    let in_page = page_ids.map_or(false, |ids| ids.contains(fragment));
+    let in_sections = match &route {
+        Route::Docs { .. } | Route::DocsTranslated { .. } => {
+            page_headings(&route).iter().any(|heading| heading.id == fragment)
+        }
+        _ => false,
+    };
+    if in_page || in_sections {
+        Ok(())
+    } else if page_ids.is_none() && !matches!(route, Route::Docs { .. }) {
+        // The page isn't prerendered, so we can't know which ids it has
+        Ok(())
+    } else {
+        Err(format!("no element with the id `{fragment}`"))
+    }
+}
+
+async fn check_external_link(client: &reqwest::Client, url: &str) -> Result<(), String> {
+    let url = if url.starts_with("//") {
+        format!("https:{url}")
+    } else {
+        url.to_string()
+    };
+    // Some servers don't implement HEAD, so fall back to GET before giving up
+    let mut response = client.head(&url).send().await;
+    if !matches!(&response, Ok(response) if response.status().is_success()) {
+        response = client.get(&url).send().await;
+    }
+    match response {
+        Ok(response) if response.status().is_success() => Ok(()),
+        Ok(response) => Err(format!("responded with {}", response.status())),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Find every generated `index.html` along with the url of the page it renders
+pub(crate) fn collect_pages(root: &Path, dir: &Path, pages: &mut Vec<(String, PathBuf)>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_pages(root, &path, pages);
+        } else if path.file_name().map_or(false, |name| name == "index.html") {
+            let relative = path.parent().unwrap().strip_prefix(root).unwrap();
+            let segments: Vec<_> = relative
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            pages.push((format!("/{}", segments.join("/")), path));
+        }
+    }
+}
+
+/// The values of every `name="..."` attribute in the html, with entities in them decoded.
+///
+/// The prerendered html always quotes attributes with double quotes, so this doesn't need a real
+/// html parser.
+fn attribute_values(html: &str, name: &str) -> Vec<String> {
+    let needle = format!(" {name}=\"");
+    let mut values = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(&needle) {
+        rest = &rest[start + needle.len()..];
+        let Some(end) = rest.find('"') else {
+            break;
+        };
+        values.push(
+            rest[..end]
+                .replace("&amp;", "&")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'"),
+        );
+        rest = &rest[end..];
+    }
+    values
+}
+
+#[test]
+fn resolves_hrefs_like_a_browser() {
+    let internal = |path: &str, fragment: Option<&str>| Href::Internal {
+        path: path.to_string(),
+        fragment: fragment.map(str::to_string),
+    };
+    let page = "/learn/0.5/guide/state";
+
+    assert_eq!(Href::parse(page, "mailto:hi@dioxuslabs.com"), Href::Skip);
+    assert_eq!(Href::parse(page, " "), Href::Skip);
+    assert_eq!(
+        Href::parse(page, "https://github.com/dioxuslabs"),
+        Href::External("https://github.com/dioxuslabs".to_string())
+    );
+    assert_eq!(
+        Href::parse(page, "https://dioxuslabs.com/blog?page=2"),
+        internal("/blog", None)
+    );
+    assert_eq!(Href::parse(page, "#hooks"), internal(page, Some("hooks")));
+    assert_eq!(
+        Href::parse(page, "data_fetching#async"),
+        internal("/learn/0.5/guide/data_fetching", Some("async"))
+    );
+    assert_eq!(
+        Href::parse(page, "../reference/rsx"),
+        internal("/learn/0.5/reference/rsx", None)
+    );
+    assert_eq!(
+        Href::parse("/learn/0.5/guide/", "state"),
+        internal("/learn/0.5/guide/state", None)
+    );
+    assert_eq!(Href::parse("/", "blog"), internal("/blog", None));
+}
+
+#[test]
+fn normalizes_paths() {
+    assert_eq!(normalize_path(""), "/");
+    assert_eq!(normalize_path("/a//b/"), "/a/b");
+    assert_eq!(normalize_path("/a/./b/../c"), "/a/c");
+    assert_eq!(normalize_path("/../a"), "/a");
+}
@@ -3,7 +3,7 @@
 use dioxus::html::input_data::keyboard_types::{Key, Modifiers};
 use dioxus::prelude::*;
 use dioxus_router::prelude::*;
-pub(crate) use docs::BookRoute;
+pub(crate) use docs::router_05::{BookRoute, TranslatedRoute};
 use serde::{Deserialize, Serialize};
 
 macro_rules! export_items {
@@ -19,18 +19,53 @@ macro_rules! export_items {
     };
 }
 
+#[cfg(feature = "doc_test")]
+pub(crate) mod hook_testing;
 pub(crate) mod icons;
+#[cfg(feature = "prebuild")]
+pub(crate) mod feed;
+#[cfg(feature = "prebuild")]
+pub(crate) mod linkcheck;
+#[cfg(feature = "prebuild")]
 pub(crate) mod sitemap;
+#[cfg(feature = "prebuild")]
+pub(crate) mod snapshot;
 
+pub(crate) mod playground;
+pub(crate) mod scroll_spy;
+pub(crate) mod search;
+pub(crate) mod search_log;
 pub(crate) mod shortcut;
 
 mod doc_examples;
+#[cfg(all(test, feature = "doc_test"))]
+mod example_tests;
+// The README sanitizer of `build/awesome.rs`, compiled here so its tests run with the crate's
+#[cfg(test)]
+#[path = "../build/readme.rs"]
+mod readme;
+// The include checks of `build/includes.rs`, for their tests. Only the helpers the tests call are used
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../build/includes.rs"]
+mod includes;
+// The markdown renderer of `build/markdown.rs` and the posts module it borrows its slugs and theme
+// from, for the renderer's tests
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../build/posts.rs"]
+mod posts;
+#[cfg(test)]
+#[allow(dead_code)]
+#[path = "../build/markdown.rs"]
+mod markdown;
 mod snippets;
 
 pub(crate) use components::*;
 pub(crate) mod components {
     export_items! {
         pub(crate) mod blog;
+        pub(crate) mod code_block;
         pub(crate) mod footer;
         pub(crate) mod homepage;
         pub(crate) mod learn;
@@ -81,8 +116,11 @@ pub(crate) enum Route {
         #[redirect("/platforms/tui", || Route::Homepage {})]
         Homepage {},
 
-        #[route("/awesome")]
-        Awesome {},
+        #[route("/awesome?:..query")]
+        Awesome { query: AwesomeQuery },
+
+        #[child("/awesome")]
+        AwesomeItem { child: AwesomeRoute },
 
         #[route("/deploy")]
         Deploy {},
@@ -90,41 +128,30 @@ pub(crate) enum Route {
         #[route("/tutorials/:id")]
         Tutorial { id: usize },
 
-        #[nest("/blog")]
-            #[route("/")]
-            BlogList {},
-            #[route("/release-050")]
-            PostRelease050 {},
-            #[route("/templates-diffing")]
-            PostTemplate {},
-            #[route("/going-fulltime")]
-            PostFulltime {},
-            #[route("/release-040")]
-            PostRelease040 {},
-            #[route("/release-030")]
-            PostRelease030 {},
-            #[route("/release-020")]
-            PostRelease020 {},
-            #[route("/introducing-dioxus")]
-            PostRelease010 {},
-        #[end_nest]
+        #[child("/blog")]
+        Blog { child: BlogRoute },
 
-        #[layout(Learn)]
-            #[nest("/learn")]
-                #[redirect("/", || Route::Docs { child: BookRoute::Index {} })]
+        #[nest("/learn")]
+            #[redirect("/", || Route::Docs { child: BookRoute::Index {} })]
 
-                #[route("/0.3/:..segments")]
-                DocsO3 {
-                    segments: Vec<String>
-                },
-                #[route("/0.4/:..segments")]
-                DocsO4 {
-                    segments: Vec<String>
-                },
+            #[route("/0.3/:..segments")]
+            DocsO3 {
+                segments: Vec<String>
+            },
+            #[route("/0.4/:..segments")]
+            DocsO4 {
+                segments: Vec<String>
+            },
+            #[redirect("/0.5/en/:..segments", |segments: Vec<String>| Route::Docs { child: english_page(&segments) })]
+
+            #[layout(Learn)]
                 #[child("/0.5")]
                 Docs { child: BookRoute },
-            #[end_nest]
-        #[end_layout]
+                // Any other language declared in the book.toml. Pages without a translation fall back to English
+                #[child("/0.5")]
+                DocsTranslated { child: TranslatedRoute },
+            #[end_layout]
+        #[end_nest]
     #[end_nest]
     #[redirect("/docs/0.3/:..segments", |segments: Vec<String>| Route::DocsO3 { segments })]
     #[redirect("/docs/:.._segments", |_segments: Vec<String>| Route::Docs { child: BookRoute::Index {} })]
@@ -141,9 +168,6 @@ pub(crate) fn app() -> Element {
     rsx! { Router::<Route> {} }
 }
 
-static SEARCH_INDEX: dioxus_search::LazySearchIndex<Route> = dioxus_search::load_search_index! {
-    "search"
-};
 mod docs {
     use crate::components::*;
     use crate::doc_examples::*;
@@ -163,7 +187,7 @@ mod docs {
     }
 
     #[component]
-    fn DemoFrame(children: Element) -> Element {
+    pub(crate) fn DemoFrame(children: Element) -> Element {
         rsx! {
             div {
                 class: "bg-white rounded-md shadow-md p-4 my-4 overflow-scroll text-black dioxus-demo",
@@ -240,7 +264,41 @@ pub enum Route {{\n\t"
         }
     }
 
-    use_mdbook::mdbook_router! {"docs-src/0.5"}
+    /// Every version of the docs has its own book in `docs-src/<version>`, with its own `BookRoute`
+    pub(crate) mod router_05 {
+        use super::*;
+        use crate::components::learn::Chapter;
+
+        // The routes of the pages in the SUMMARY.md and the chapters of the sidebar, generated by
+        // `build.rs`
+        include!(concat!(env!("OUT_DIR"), "/summary_0_5.rs"));
+
+        /// The folder the markdown of the book lives in, relative to the root of the repo
+        pub(crate) const BOOK_DIR: &str = "docs-src/0.5/en";
+
+        // Every markdown file in `BOOK_DIR`, collected by `build.rs`
+        include!(concat!(env!("OUT_DIR"), "/book_sources_0_5.rs"));
+
+        // The languages declared in the book.toml, collected by `build.rs`
+        include!(concat!(env!("OUT_DIR"), "/languages_0_5.rs"));
+
+        // Every page in every language rendered to rsx, and the routes of the translated pages,
+        // generated by `build.rs`
+        include!(concat!(env!("OUT_DIR"), "/pages_0_5.rs"));
+
+        impl BookRoute {
+            /// The path of the markdown file this page is rendered from, relative to [`BOOK_DIR`]
+            pub(crate) fn source_path(&self) -> Option<&'static str> {
+                let url = self.to_string();
+                let url = url.trim_matches('/');
+                let candidates = [format!("{url}.md"), format!("{url}/index.md")];
+                candidates.iter().find_map(|candidate| {
+                    let candidate = candidate.trim_start_matches('/');
+                    BOOK_SOURCES.iter().copied().find(|source| *source == candidate)
+                })
+            }
+        }
+    }
 }
 
 fn main() {
@@ -257,37 +315,55 @@ fn main() {
             .with_level(LevelFilter::Error)
             .init()
             .unwrap();
+
+        // `cargo run --features prebuild -- linkcheck [--external]` checks the site prebuilt into docs/
+        if std::env::args().nth(1).as_deref() == Some("linkcheck") {
+            let check_external = std::env::args().any(|arg| arg == "--external");
+            let ok = tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(linkcheck::run(std::path::Path::new("./docs"), check_external));
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+
+        // `cargo run --features prebuild -- validate-awesome [path]` checks an awesome.json
+        if std::env::args().nth(1).as_deref() == Some("validate-awesome") {
+            let path = std::env::args().nth(2).unwrap_or_else(|| "awesome.json".to_string());
+            let ok = components::awesome::validate::validate_file(std::path::Path::new(&path));
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+
+        // `cargo run --features prebuild -- vendor-awesome` refreshes the awesome snapshot. The pages
+        // of the awesome entries are generated from it at build time, so CI runs this first
+        if std::env::args().nth(1).as_deref() == Some("vendor-awesome") {
+            tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(components::awesome::snapshot::vendor())
+                .unwrap();
+            println!("vendored the awesome list");
+            return;
+        }
+
+        // `cargo run --features prebuild -- search-misses [--update-suggestions]` exports the log of
+        // searches that found little, and can update the suggestions in the repo from the prebuilt index
+        if std::env::args().nth(1).as_deref() == Some("search-misses") {
+            let update = std::env::args().any(|arg| arg == "--update-suggestions");
+            search_log::export(&std::path::Path::new("./docs").join(search::INDEX_DIR), update).unwrap();
+            return;
+        }
+
+        // `cargo run --features prebuild -- snapshot [--update]` diffs the pages against page_snapshots/
+        if std::env::args().nth(1).as_deref() == Some("snapshot") {
+            let update = std::env::args().any(|arg| arg == "--update");
+            let ok = tokio::runtime::Runtime::new()
+                .unwrap()
+                .block_on(snapshot::run(update));
+            std::process::exit(if ok { 0 } else { 1 });
+        }
+
         tokio::runtime::Runtime::new()
             .unwrap()
             .block_on(async move {
-                let index_html = std::fs::read_to_string("docs/index.html").unwrap();
-                let main_tag = r#"<div id="main">"#;
-                let (before_body, after_body) =
-                    index_html.split_once(main_tag).expect("main id not found");
-                let after_body = after_body
-                    .split_once("</div>")
-                    .expect("main id not found")
-                    .1;
-                let wrapper = DefaultRenderer {
-                    before_body: before_body.to_string() + main_tag,
-                    after_body: "</div>".to_string() + after_body,
-                };
-                let mut renderer = IncrementalRenderer::builder()
-                    .static_dir("docs_static")
-                    .map_path(|route| {
-                        let mut path = std::env::current_dir().unwrap();
-                        path.push("docs_static");
-                        for segment in route.split('/') {
-                            path.push(segment);
-                        }
-                        println!("built: {}", path.display());
-                        path
-                    })
-                    .build();
-                renderer.renderer_mut().pre_render = true;
-                pre_cache_static_routes::<Route, _>(&mut renderer, &wrapper)
-                    .await
-                    .unwrap();
+                prerender(std::env::current_dir().unwrap().join("docs_static")).await;
 
                 // Copy everything from docs_static to docs
                 let mut options = fs_extra::dir::CopyOptions::new();
@@ -299,22 +375,77 @@ fn main() {
             });
         println!("prebuilt");
 
-        dioxus_search::SearchIndex::<Route>::create(
-            "search",
-            dioxus_search::BaseDirectoryMapping::new(std::path::PathBuf::from("./docs")).map(
-                |route: Route| {
-                    let route = route.to_string();
-                    let mut path = std::path::PathBuf::default();
-                    for (i, segment) in route.split('/').enumerate() {
-                        path.push(segment);
-                    }
-                    Some(path.join("index.html"))
-                },
-            ),
-        );
+        sitemap::generate(std::path::Path::new("./docs")).unwrap();
+        println!("generated sitemap");
+        feed::generate(std::path::Path::new("./docs")).unwrap();
+        println!("generated blog feeds");
+
+        for chunk in search::search_chunks() {
+            dioxus_search::SearchIndex::<Route>::create(
+                chunk.name,
+                dioxus_search::BaseDirectoryMapping::new(std::path::PathBuf::from("./docs")).map(
+                    move |route: Route| {
+                        if search::section_of(&route) != chunk.section {
+                            return None;
+                        }
+                        let route = route.to_string();
+                        let route = route.split('?').next().unwrap_or_default();
+                        let mut path = std::path::PathBuf::default();
+                        for segment in route.split('/') {
+                            path.push(segment);
+                        }
+                        Some(path.join("index.html"))
+                    },
+                ),
+            );
+
+            // The site fetches the chunks at runtime, so they are served next to the pages
+            let index_dir = std::path::Path::new("./docs").join(search::INDEX_DIR);
+            std::fs::create_dir_all(&index_dir).unwrap();
+            std::fs::copy(
+                search::created_index_path(chunk.name),
+                index_dir.join(format!("{}.bin", chunk.name)),
+            )
+            .unwrap();
+        }
+        println!("generated search index");
+        search_log::update_suggestions(&std::path::Path::new("./docs").join(search::INDEX_DIR)).unwrap();
         return;
     }
 
     #[cfg(not(feature = "prebuild"))]
     launch(app);
 }
+
+/// Render every static route into `out_dir`, inside the `docs/index.html` that `dx build` outputs
+#[cfg(feature = "prebuild")]
+pub(crate) async fn prerender(out_dir: std::path::PathBuf) {
+    let index_html = std::fs::read_to_string("docs/index.html").unwrap();
+    let main_tag = r#"<div id="main">"#;
+    let (before_body, after_body) = index_html.split_once(main_tag).expect("main id not found");
+    let after_body = after_body
+        .split_once("</div>")
+        .expect("main id not found")
+        .1;
+    let wrapper = DefaultRenderer {
+        before_body: before_body.to_string() + main_tag,
+        after_body: "</div>".to_string() + after_body,
+    };
+    let mut renderer = IncrementalRenderer::builder()
+        .static_dir(out_dir.clone())
+        .map_path(move |route| {
+            // Routes with a query, like the filters of the awesome page, render to the page without it
+            let route = route.split('?').next().unwrap_or_default();
+            let mut path = out_dir.clone();
+            for segment in route.split('/') {
+                path.push(segment);
+            }
+            println!("built: {}", path.display());
+            path
+        })
+        .build();
+    renderer.renderer_mut().pre_render = true;
+    pre_cache_static_routes::<Route, _>(&mut renderer, &wrapper)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,558 @@
+//! A playground that compiles the examples of the docs to wasm so readers can run and edit them.
+//!
+//! The rust playground doesn't have dioxus, so we run our own when the site is served with the
+//! `playground` feature. [`compile_playground`] takes the source of a component called `App`, builds
+//! it against the same dioxus the site is built with and returns a self-contained html page that runs
+//! it. The page is meant for the `srcdoc` of a sandboxed iframe, so everything it needs is inlined.
+//!
+//! Compiling runs the code of macros and can read any file the compiler can, so the feature isn't part
+//! of `server`, and builds only run inside the sandbox `PLAYGROUND_SANDBOX` names. It is the command
+//! `cargo` and `wasm-bindgen` are run under, like `nsjail --config playground.cfg --`, and should mount
+//! the toolchain and vendored dependencies read-only with no network and nothing else of the host but
+//! the build directories. On top of that, sources that read files or the environment at compile time
+//! are rejected, paths of the host are removed from compile errors and every reader may only start a
+//! few builds at a time.
+//!
+//! Builds are cached by the hash of their source in `target/playground` (or `PLAYGROUND_DIR`).
+
+use dioxus::prelude::*;
+
+/// Sources longer than this are rejected before we start a build
+pub(crate) const MAX_SOURCE_LEN: usize = 20_000;
+
+/// Compile the source of an `App` component into a page that renders it
+#[server]
+pub(crate) async fn compile_playground(source: String) -> Result<String, ServerFnError> {
+    #[cfg(not(feature = "playground"))]
+    return Err(ServerFnError::ServerError(
+        "the playground isn't enabled on this server".to_string(),
+    ));
+
+    #[cfg(feature = "playground")]
+    {
+        if source.len() > MAX_SOURCE_LEN {
+            return Err(ServerFnError::ServerError(format!(
+                "the source is longer than {MAX_SOURCE_LEN} bytes"
+            )));
+        }
+        if let Some(item) = forbidden_item(&source) {
+            return Err(ServerFnError::ServerError(format!(
+                "{item} isn't available in the playground"
+            )));
+        }
+        let headers: axum::http::HeaderMap = extract()
+            .await
+            .map_err(|_| ServerFnError::ServerError("couldn't read the request".to_string()))?;
+        build::compile(&build::client(&headers), &source)
+            .await
+            .map_err(ServerFnError::ServerError)
+    }
+}
+
+/// Macros that read files or the environment of the server at compile time
+const FORBIDDEN_MACROS: &[&str] = &["include", "include_str", "include_bytes", "env", "option_env"];
+
+/// Find the first use of something the playground doesn't compile: a macro that reads files or the
+/// environment at compile time, or a `#[path]` that points a module at a file of the server.
+///
+/// Comments and literals are skipped, and the names are rejected even without a `!`, so they can't be
+/// smuggled in as the argument of a `macro_rules!`.
+fn forbidden_item(source: &str) -> Option<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut index = 0;
+    let mut bracket_depth = 0;
+    // The bracket depths the attributes we are in were opened at. `cfg_attr` can put a `path` anywhere
+    // in an attribute.
+    let mut attributes: Vec<usize> = Vec::new();
+    let mut attribute_start = None;
+
+    while index < chars.len() {
+        let c = chars[index];
+        let next = chars.get(index + 1).copied();
+        match c {
+            '/' if next == Some('/') => {
+                while index < chars.len() && chars[index] != '\n' {
+                    index += 1;
+                }
+            }
+            '/' if next == Some('*') => {
+                let mut depth = 0;
+                while index < chars.len() {
+                    match (chars[index], chars.get(index + 1).copied()) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            index += 2;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            index += 2;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => index += 1,
+                    }
+                }
+                continue;
+            }
+            '"' => index = skip_string(&chars, index + 1),
+            '\'' => {
+                // A char literal, unless it is a lifetime or label
+                let end = match next {
+                    Some('\\') => chars[index + 2..].iter().position(|c| *c == '\'').map(|end| index + 3 + end),
+                    Some(_) if chars.get(index + 2) == Some(&'\'') => Some(index + 3),
+                    _ => None,
+                };
+                if let Some(end) = end {
+                    index = end;
+                    continue;
+                }
+            }
+            '#' if next == Some('[') || (next == Some('!') && chars.get(index + 2) == Some(&'[')) => {
+                attribute_start = Some(bracket_depth);
+            }
+            '[' => {
+                if attribute_start.take() == Some(bracket_depth) {
+                    attributes.push(bracket_depth);
+                }
+                bracket_depth += 1;
+            }
+            ']' => {
+                bracket_depth = bracket_depth.saturating_sub(1);
+                if attributes.last() == Some(&bracket_depth) {
+                    attributes.pop();
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = index;
+                while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                    index += 1;
+                }
+                let mut ident: String = chars[start..index].iter().collect();
+                // Raw strings and byte strings start like identifiers
+                if matches!(ident.as_str(), "r" | "b" | "br" | "c" | "cr") {
+                    let mut hashes = 0;
+                    while chars.get(index + hashes) == Some(&'#') {
+                        hashes += 1;
+                    }
+                    if chars.get(index + hashes) == Some(&'"') {
+                        index = if ident.ends_with('r') {
+                            skip_raw_string(&chars, index + hashes + 1, hashes)
+                        } else {
+                            skip_string(&chars, index + 1)
+                        };
+                        continue;
+                    }
+                    if ident == "r" && hashes == 1 {
+                        // A raw identifier like `r#include_str`
+                        index += 1;
+                        let start = index;
+                        while index < chars.len() && (chars[index].is_alphanumeric() || chars[index] == '_') {
+                            index += 1;
+                        }
+                        ident = chars[start..index].iter().collect();
+                    }
+                }
+                if FORBIDDEN_MACROS.contains(&ident.as_str()) {
+                    return Some(format!("`{ident}!`"));
+                }
+                if ident == "path" && !attributes.is_empty() {
+                    return Some("`#[path]`".to_string());
+                }
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// The index after the end of a string literal whose contents start at `index`
+fn skip_string(chars: &[char], mut index: usize) -> usize {
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 2,
+            '"' => return index + 1,
+            _ => index += 1,
+        }
+    }
+    index
+}
+
+/// The index after the end of a raw string literal with `hashes` hashes whose contents start at `index`
+fn skip_raw_string(chars: &[char], mut index: usize, hashes: usize) -> usize {
+    while index < chars.len() {
+        if chars[index] == '"' && (1..=hashes).all(|offset| chars.get(index + offset) == Some(&'#')) {
+            return index + 1 + hashes;
+        }
+        index += 1;
+    }
+    index
+}
+
+#[cfg(feature = "playground")]
+mod build {
+    use base64::Engine;
+    use std::hash::{Hash, Hasher};
+    use std::path::{Path, PathBuf};
+    use std::process::Stdio;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    /// Builds share a target directory so dioxus is only compiled once, which means only one can run
+    /// at a time
+    static BUILD_LOCK: once_cell::sync::Lazy<tokio::sync::Mutex<()>> =
+        once_cell::sync::Lazy::new(|| tokio::sync::Mutex::new(()));
+
+    /// How long a single build may take before we give up on it
+    const BUILD_TIMEOUT: Duration = Duration::from_secs(180);
+
+    /// The most builds a reader may start per [`RATE_WINDOW`]. Cached pages don't count.
+    const MAX_BUILDS_PER_CLIENT: usize = 5;
+    const RATE_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+    /// The most builds waiting for [`BUILD_LOCK`] at once, from every reader together
+    const MAX_QUEUED_BUILDS: usize = 4;
+
+    /// The reader and time of every build started in the current rate window
+    static RECENT_BUILDS: Mutex<Vec<(String, Instant)>> = Mutex::new(Vec::new());
+
+    static QUEUED_BUILDS: AtomicUsize = AtomicUsize::new(0);
+
+    const CRATE_NAME: &str = "playground";
+
+    /// Everything in the prelude of the docs examples is available without imports
+    const PRELUDE: &str = "#![allow(non_snake_case, unused)]\nuse dioxus::prelude::*;\n\n";
+
+    const MAIN: &str = "\n\nfn main() {\n    launch(App);\n}\n";
+
+    fn cache_dir() -> PathBuf {
+        std::env::var_os("PLAYGROUND_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("target/playground"))
+    }
+
+    fn source_hash(source: &str) -> String {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        source.hash(&mut hasher);
+        format!("{:016x}", hasher.finish())
+    }
+
+    /// Who is asking for a build. The server is meant to run behind a proxy that sets
+    /// `X-Forwarded-For`, without one every reader shares the same limit.
+    pub(super) fn client(headers: &axum::http::HeaderMap) -> String {
+        headers
+            .get("x-forwarded-for")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .map_or_else(|| "unknown".to_string(), |client| client.trim().to_string())
+    }
+
+    pub(super) async fn compile(client: &str, source: &str) -> Result<String, String> {
+        let cache_dir = cache_dir();
+        let page = cache_dir.join("pages").join(format!("{}.html", source_hash(source)));
+        if let Ok(html) = tokio::fs::read_to_string(&page).await {
+            return Ok(html);
+        }
+
+        let sandbox = sandbox()?;
+        take_build_slot(client)?;
+        let _queued = QueuedBuild::enter()?;
+        let _lock = BUILD_LOCK.lock().await;
+        // Someone else may have built the same source while we were waiting
+        if let Ok(html) = tokio::fs::read_to_string(&page).await {
+            return Ok(html);
+        }
+
+        let crate_dir = tempdir(source)?;
+        let result = build_page(&sandbox, &crate_dir, &cache_dir.join("target"), source).await;
+        _ = tokio::fs::remove_dir_all(&crate_dir).await;
+        let html = result?;
+
+        tokio::fs::create_dir_all(page.parent().unwrap())
+            .await
+            .map_err(|err| err.to_string())?;
+        tokio::fs::write(&page, &html)
+            .await
+            .map_err(|err| err.to_string())?;
+        Ok(html)
+    }
+
+    /// Count a build against the limit of the reader, or refuse it if they reached it
+    fn take_build_slot(client: &str) -> Result<(), String> {
+        let mut recent = RECENT_BUILDS.lock().unwrap();
+        let now = Instant::now();
+        recent.retain(|(_, time)| now.duration_since(*time) < RATE_WINDOW);
+        if recent.iter().filter(|(recent, _)| recent == client).count() >= MAX_BUILDS_PER_CLIENT {
+            return Err("too many builds, try again in a few minutes".to_string());
+        }
+        recent.push((client.to_string(), now));
+        Ok(())
+    }
+
+    /// A build waiting for or holding [`BUILD_LOCK`], which leaves the queue when dropped
+    struct QueuedBuild;
+
+    impl QueuedBuild {
+        fn enter() -> Result<Self, String> {
+            if QUEUED_BUILDS.fetch_add(1, Ordering::SeqCst) >= MAX_QUEUED_BUILDS {
+                QUEUED_BUILDS.fetch_sub(1, Ordering::SeqCst);
+                return Err("the playground is busy, try again in a minute".to_string());
+            }
+            Ok(Self)
+        }
+    }
+
+    impl Drop for QueuedBuild {
+        fn drop(&mut self) {
+            QUEUED_BUILDS.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    /// The command builds run under, split into its arguments. Without one nothing is built.
+    fn sandbox() -> Result<Vec<String>, String> {
+        let sandbox: Vec<String> = std::env::var("PLAYGROUND_SANDBOX")
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        if sandbox.is_empty() {
+            return Err("the playground has no sandbox to build in".to_string());
+        }
+        Ok(sandbox)
+    }
+
+    /// A fresh crate for a single build, outside of the repo so it can't see any of our files
+    fn tempdir(source: &str) -> Result<PathBuf, String> {
+        let dir = std::env::temp_dir()
+            .join("dioxus-playground")
+            .join(source_hash(source));
+        std::fs::create_dir_all(dir.join("src")).map_err(|err| err.to_string())?;
+        Ok(dir)
+    }
+
+    async fn build_page(
+        sandbox: &[String],
+        crate_dir: &Path,
+        target_dir: &Path,
+        source: &str,
+    ) -> Result<String, String> {
+        let write = |path: PathBuf, contents: String| {
+            std::fs::write(path, contents).map_err(|err| err.to_string())
+        };
+        write(crate_dir.join("Cargo.toml"), manifest())?;
+        write(crate_dir.join("src/main.rs"), format!("{PRELUDE}{source}{MAIN}"))?;
+        // Pin the build to the exact dioxus the site itself was built with
+        let lock_file = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.lock");
+        if lock_file.exists() {
+            std::fs::copy(lock_file, crate_dir.join("Cargo.lock")).map_err(|err| err.to_string())?;
+        }
+
+        // The sandbox has no network, so the dependencies come from the vendored ones
+        let mut cargo = sandboxed(sandbox, "cargo");
+        cargo
+            .current_dir(crate_dir)
+            .args(["build", "--release", "--offline", "--target", "wasm32-unknown-unknown"])
+            .env("CARGO_TARGET_DIR", target_dir);
+        run(cargo)
+            .await
+            .map_err(|err| hide_host_paths(&err, crate_dir, target_dir))?;
+
+        let out_dir = crate_dir.join("out");
+        let wasm = target_dir.join(format!("wasm32-unknown-unknown/release/{CRATE_NAME}.wasm"));
+        let mut bindgen = sandboxed(sandbox, "wasm-bindgen");
+        bindgen
+            .arg("--target")
+            .arg("web")
+            .arg("--no-typescript")
+            .arg("--out-dir")
+            .arg(&out_dir)
+            .arg(&wasm);
+        run(bindgen)
+            .await
+            .map_err(|err| hide_host_paths(&err, crate_dir, target_dir))?;
+
+        bundle(&out_dir)
+    }
+
+    fn manifest() -> String {
+        format!(
+            r#"[package]
+name = "{CRATE_NAME}"
+version = "0.0.0"
+edition = "2021"
+
+[dependencies]
+dioxus = {{ git = "https://github.com/dioxuslabs/dioxus", features = ["web"] }}
+
+[profile.release]
+opt-level = "z"
+debug = false
+
+[workspace]
+"#
+        )
+    }
+
+    /// A command run in the sandbox that only sees the environment it needs to find the toolchain
+    fn sandboxed(sandbox: &[String], program: &str) -> tokio::process::Command {
+        let mut command = tokio::process::Command::new(&sandbox[0]);
+        command.args(&sandbox[1..]).arg(program);
+        command.env_clear();
+        for var in ["PATH", "HOME", "CARGO_HOME", "RUSTUP_HOME", "RUSTUP_TOOLCHAIN"] {
+            if let Some(value) = std::env::var_os(var) {
+                command.env(var, value);
+            }
+        }
+        command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        command
+    }
+
+    /// Run a command, turning a failure into its output so compile errors make it to the reader
+    async fn run(mut command: tokio::process::Command) -> Result<(), String> {
+        let output = tokio::time::timeout(BUILD_TIMEOUT, command.output())
+            .await
+            .map_err(|_| format!("the build took longer than {} seconds", BUILD_TIMEOUT.as_secs()))?
+            .map_err(|err| err.to_string())?;
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).into_owned())
+        }
+    }
+
+    /// Replace the paths of the server in compiler output with placeholders, so errors show where the
+    /// reader's code went wrong without telling them how the server is laid out
+    fn hide_host_paths(output: &str, crate_dir: &Path, target_dir: &Path) -> String {
+        let env_path = |var: &str| std::env::var_os(var).map(PathBuf::from);
+        let home = env_path("HOME");
+        let mut paths: Vec<(PathBuf, &str)> = vec![
+            (crate_dir.to_path_buf(), "/playground"),
+            (target_dir.to_path_buf(), "/target"),
+            (std::env::temp_dir(), "/tmp"),
+            (PathBuf::from(env!("CARGO_MANIFEST_DIR")), "/site"),
+        ];
+        let cargo_home = env_path("CARGO_HOME").or_else(|| home.as_ref().map(|home| home.join(".cargo")));
+        let rustup_home = env_path("RUSTUP_HOME").or_else(|| home.as_ref().map(|home| home.join(".rustup")));
+        paths.extend(cargo_home.map(|path| (path, "~/.cargo")));
+        paths.extend(rustup_home.map(|path| (path, "~/.rustup")));
+        paths.extend(home.map(|path| (path, "~")));
+        if let Ok(absolute) = target_dir.canonicalize() {
+            paths.push((absolute, "/target"));
+        }
+
+        // Longer paths first, so a path inside another one gets its own placeholder
+        paths.sort_by_key(|(path, _)| std::cmp::Reverse(path.as_os_str().len()));
+        let mut output = output.to_string();
+        for (path, placeholder) in paths {
+            let path = path.to_string_lossy();
+            let path = path.trim_end_matches('/');
+            if path.len() > 1 {
+                output = output.replace(path, placeholder);
+            }
+        }
+        output
+    }
+
+    /// Inline the output of wasm-bindgen into a single html page
+    fn bundle(out_dir: &Path) -> Result<String, String> {
+        let read = |path: PathBuf| std::fs::read(&path).map_err(|err| format!("{}: {err}", path.display()));
+        let mut js = String::from_utf8_lossy(&read(out_dir.join(format!("{CRATE_NAME}.js")))?).into_owned();
+        let wasm = read(out_dir.join(format!("{CRATE_NAME}_bg.wasm")))?;
+
+        // The glue imports the js snippets of dependencies by relative path, which a data url can't
+        // resolve, so those become data urls too
+        let snippets_dir = out_dir.join("snippets");
+        let mut snippets = Vec::new();
+        if snippets_dir.exists() {
+            collect_files(&snippets_dir, &mut snippets);
+        }
+        for snippet in snippets {
+            let relative = snippet.strip_prefix(out_dir).unwrap();
+            let specifier = format!("./{}", relative.to_string_lossy().replace('\\', "/"));
+            js = js.replace(&specifier, &data_url(&read(snippet.clone())?));
+        }
+
+        let base64 = base64::engine::general_purpose::STANDARD;
+        Ok(format!(
+            r#"<!DOCTYPE html>
+<html>
+  <head><meta charset="utf-8" /></head>
+  <body>
+    <div id="main"></div>
+    <script type="module">
+      import init from "{}";
+      const wasm = Uint8Array.from(atob("{}"), (c) => c.charCodeAt(0));
+      init(wasm);
+    </script>
+  </body>
+</html>
+"#,
+            data_url(js.as_bytes()),
+            base64.encode(wasm)
+        ))
+    }
+
+    fn data_url(js: &[u8]) -> String {
+        let base64 = base64::engine::general_purpose::STANDARD;
+        format!("data:text/javascript;base64,{}", base64.encode(js))
+    }
+
+    fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_files(&path, files);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::forbidden_item;
+
+    #[test]
+    fn rejects_reading_the_server() {
+        for source in [
+            r#"const A: &str = include_str!("/etc/passwd");"#,
+            r#"const A: &[u8] = std::include_bytes!("/etc/passwd");"#,
+            r#"const A: &str = include_str  !("/etc/passwd");"#,
+            r#"const A: &str = r#include_str!("/etc/passwd");"#,
+            r#"macro_rules! m { ($m:ident) => { $m!("/etc/passwd") } } const A: &str = m!(include_str);"#,
+            r#"const A: &str = env!("GITHUB_TOKEN");"#,
+            r#"const A: Option<&str> = option_env!("GITHUB_TOKEN");"#,
+            "#[path = \"/etc/passwd\"]\nmod secrets;",
+            "#[cfg_attr(all(), path = \"/etc/passwd\")]\nmod secrets;",
+            "#![path = \"/etc\"]",
+            "const A: [u8; 1] = [#[cfg_attr(all(), path = \"/etc\")] 1];",
+        ] {
+            assert!(forbidden_item(source).is_some(), "{source}");
+        }
+    }
+
+    #[test]
+    fn accepts_the_examples() {
+        for source in [
+            "fn App() -> Element {\n    let path = \"include_str!\";\n    rsx! { \"{path}\" }\n}",
+            "// include_str!(\"/etc/passwd\")\n#[component]\nfn App() -> Element { None }",
+            "/* env!(\"A\") /* nested */ */ fn App() -> Element { None }",
+            "fn App<'a>() -> Element { let c = '\"'; let e = '\\''; rsx! { div { class: r#\"env\"#, \"{c}{e}\" } } }",
+            "fn f() -> [u8; 1] { [#[allow(unused)] 1] }\nfn g(path: &str) {}",
+            "#[derive(Clone)]\nstruct Props { path: String }\nfn f(v: [u8; 2]) -> u8 { v[0] }",
+        ] {
+            assert_eq!(forbidden_item(source), None, "{source}");
+        }
+    }
+}
@@ -0,0 +1,117 @@
+#![allow(unused)]
+use dioxus::prelude::*;
+use std::rc::Rc;
+
+/// The id of the section of the current page that is being read
+static CURRENT_SECTION: GlobalSignal<Option<String>> = Signal::global(|| None);
+
+/// How far below the top of the viewport a heading still counts as being read. The header is sticky,
+/// so anything above this is hidden behind it.
+const HEADER_OFFSET: f64 = 120.0;
+
+/// Track which of the given sections is being read as the user scrolls.
+///
+/// The sections are element ids in the order they appear on the page. Only one component should
+/// drive the scroll spy at a time; everyone else can read the result with [`use_current_section`].
+pub(crate) fn use_scroll_spy(
+    sections: impl FnMut() -> Vec<String> + 'static,
+) -> ReadOnlySignal<Option<String>> {
+    let sections = use_memo(sections);
+
+    #[cfg(feature = "web")]
+    {
+        use wasm_bindgen::closure::Closure;
+        use wasm_bindgen::JsCast;
+
+        let listener = use_hook(move || {
+            let listener: Closure<dyn FnMut(web_sys::Event)> =
+                Closure::new(move |_: web_sys::Event| {
+                    let active = active_section(&sections.peek());
+                    if *CURRENT_SECTION.peek() != active {
+                        *CURRENT_SECTION.write() = active;
+                    }
+                });
+            let document = web_sys::window().unwrap().document().unwrap();
+            document
+                .add_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+                .unwrap();
+            Rc::new(listener)
+        });
+
+        use_drop(move || {
+            let document = web_sys::window().unwrap().document().unwrap();
+            _ = document.remove_event_listener_with_callback(
+                "scroll",
+                listener.as_ref().as_ref().unchecked_ref(),
+            );
+            *CURRENT_SECTION.write() = None;
+        });
+
+        // The page changed, so the old section is gone. A section named in the fragment of the url,
+        // like the ones search results link to, is scrolled to now that the page has rendered.
+        use_effect(move || {
+            let sections = sections.read();
+            let linked = web_sys::window()
+                .and_then(|window| window.location().hash().ok())
+                .map(|hash| hash.trim_start_matches('#').to_string())
+                .filter(|id| sections.contains(id));
+            match linked {
+                Some(id) => scroll_to_section(&id),
+                None => *CURRENT_SECTION.write() = active_section(&sections),
+            }
+        });
+    }
+
+    use_current_section()
+}
+
+/// The id of the section that is being read, as tracked by [`use_scroll_spy`]
+pub(crate) fn use_current_section() -> ReadOnlySignal<Option<String>> {
+    CURRENT_SECTION.signal().into()
+}
+
+/// Smoothly scroll to a section and put it in the fragment of the url.
+///
+/// Only the fragment is touched, the path and query of the current route stay the same.
+pub(crate) fn scroll_to_section(id: &str) {
+    #[cfg(feature = "web")]
+    {
+        let window = web_sys::window().unwrap();
+        let Some(element) = window.document().and_then(|document| document.get_element_by_id(id))
+        else {
+            return;
+        };
+
+        let mut options = web_sys::ScrollIntoViewOptions::new();
+        options.behavior(web_sys::ScrollBehavior::Smooth);
+        element.scroll_into_view_with_scroll_into_view_options(&options);
+
+        if let Ok(history) = window.history() {
+            _ = history.replace_state_with_url(
+                &wasm_bindgen::JsValue::NULL,
+                "",
+                Some(&format!("#{id}")),
+            );
+        }
+
+        *CURRENT_SECTION.write() = Some(id.to_string());
+    }
+}
+
+/// The last section whose heading has been scrolled past
+#[cfg(feature = "web")]
+fn active_section(sections: &[String]) -> Option<String> {
+    let document = web_sys::window()?.document()?;
+
+    let mut active = sections.first().cloned();
+    for id in sections {
+        let Some(element) = document.get_element_by_id(id) else {
+            continue;
+        };
+        if element.get_bounding_client_rect().top() > HEADER_OFFSET {
+            break;
+        }
+        active = Some(id.clone());
+    }
+    active
+}
@@ -0,0 +1,301 @@
+//! The search index of the site, split into one chunk per section.
+//!
+//! Prebuild writes one index per chunk into `docs/search/`, and the browser fetches the chunks the
+//! first time the search modal opens instead of compiling them into the wasm. A change to one section of
+//! the docs only changes that chunk, and the service worker keeps the fetched chunks so search keeps
+//! working offline. Searching queries every loaded chunk and merges the results by score.
+
+use crate::*;
+use dioxus_search::{SearchIndex, SearchResult};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The chunk every route outside of the docs sections below ends up in
+pub(crate) const SITE_SECTION: &str = "site";
+
+/// The folder of the site the chunks of the index are served from
+pub(crate) const INDEX_DIR: &str = "search";
+
+pub(crate) struct SearchChunk {
+    /// The first segment of the docs urls in this chunk, or [`SITE_SECTION`]
+    pub(crate) section: &'static str,
+    /// The name of the index file prebuild writes for this chunk
+    pub(crate) name: &'static str,
+}
+
+pub(crate) static SEARCH_CHUNKS: &[SearchChunk] = &[
+    SearchChunk { section: SITE_SECTION, name: "search-site" },
+    SearchChunk { section: "getting_started", name: "search-getting-started" },
+    SearchChunk { section: "guide", name: "search-guide" },
+    SearchChunk { section: "reference", name: "search-reference" },
+    SearchChunk { section: "router", name: "search-router" },
+    SearchChunk { section: "cookbook", name: "search-cookbook" },
+    SearchChunk { section: "CLI", name: "search-cli" },
+    SearchChunk { section: "contributing", name: "search-contributing" },
+    SearchChunk { section: "migration", name: "search-migration" },
+];
+
+/// Every chunk of the index: the sections of the English docs and the site, then one chunk for every
+/// language the docs are translated to
+pub(crate) fn search_chunks() -> impl Iterator<Item = &'static SearchChunk> {
+    SEARCH_CHUNKS
+        .iter()
+        .chain(crate::docs::router_05::TRANSLATION_SEARCH_CHUNKS)
+}
+
+thread_local! {
+    /// The chunks of the index that have been loaded so far, by name
+    static LOADED_CHUNKS: RefCell<Vec<(&'static str, Rc<SearchIndex<Route>>)>> =
+        RefCell::new(Vec::new());
+}
+
+/// Why a search has no results to show
+#[derive(Debug)]
+pub(crate) enum SearchError {
+    /// None of the chunks of the index could be loaded yet
+    NotLoaded,
+    /// Every loaded chunk failed to search
+    Index(stork_lib::SearchError),
+}
+
+/// Fetch every chunk of the index that isn't loaded yet. Chunks that fail to load are fetched again
+/// the next time this is called.
+pub(crate) async fn load_chunks() {
+    let missing: Vec<&'static SearchChunk> = search_chunks()
+        .filter(|chunk| {
+            LOADED_CHUNKS.with_borrow(|loaded| !loaded.iter().any(|(name, _)| *name == chunk.name))
+        })
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+
+    let fetched = futures::future::join_all(missing.into_iter().map(|chunk| async move {
+        match fetch_index_file(&format!("{}.bin", chunk.name)).await {
+            Ok(bytes) => Some((chunk.name, bytes)),
+            Err(err) => {
+                log::error!("failed to fetch the {} index: {err}", chunk.name);
+                None
+            }
+        }
+    }))
+    .await;
+
+    for (name, bytes) in fetched.into_iter().flatten() {
+        load_chunk(name, bytes);
+    }
+}
+
+/// Fetch a file prebuild wrote next to the chunks of the index
+pub(crate) async fn fetch_index_file(file: &str) -> Result<Vec<u8>, reqwest::Error> {
+    #[cfg(feature = "web")]
+    let origin = web_sys::window()
+        .and_then(|window| window.location().origin().ok())
+        .unwrap_or_default();
+    #[cfg(not(feature = "web"))]
+    let origin = "https://dioxuslabs.com";
+
+    let url = format!("{origin}/{INDEX_DIR}/{file}");
+    let response = reqwest::get(url).await?.error_for_status()?;
+    Ok(response.bytes().await?.to_vec())
+}
+
+/// Where `SearchIndex::create` writes the index of a chunk during prebuild
+#[cfg(feature = "prebuild")]
+pub(crate) fn created_index_path(name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(format!("{name}.bin"))
+}
+
+/// Load every chunk of the index prebuild wrote into `dir`
+#[cfg(feature = "prebuild")]
+pub(crate) fn load_written_chunks(dir: &std::path::Path) -> std::io::Result<()> {
+    for chunk in search_chunks() {
+        load_chunk(chunk.name, std::fs::read(dir.join(format!("{}.bin", chunk.name)))?);
+    }
+    Ok(())
+}
+
+/// Load the bytes of a chunk of the index, replacing the chunk if it was already loaded
+pub(crate) fn load_chunk(name: &'static str, bytes: Vec<u8>) {
+    let index = Rc::new(SearchIndex::<Route>::from_bytes(name, bytes));
+    LOADED_CHUNKS.with_borrow_mut(|loaded| {
+        loaded.retain(|(loaded, _)| *loaded != name);
+        loaded.push((name, index));
+    });
+}
+
+/// The section of the site a route is indexed in
+pub(crate) fn section_of(route: &Route) -> &'static str {
+    if let Route::DocsTranslated { child } = route {
+        return language(child.language()).map_or(SITE_SECTION, |language| language.code);
+    }
+    let Route::Docs { child } = route else {
+        return SITE_SECTION;
+    };
+    let url = child.to_string();
+    let first_segment = url.trim_start_matches('/').split('/').next().unwrap_or_default();
+    SEARCH_CHUNKS
+        .iter()
+        .map(|chunk| chunk.section)
+        .find(|section| *section == first_segment)
+        .unwrap_or(SITE_SECTION)
+}
+
+/// Search every loaded chunk of the index, best results first.
+///
+/// Chunks that fail to search are skipped as long as at least one chunk answered, so a single broken
+/// chunk doesn't take down the whole search.
+pub(crate) fn search(query: &str) -> Result<Vec<SearchResult<Route>>, SearchError> {
+    let loaded = LOADED_CHUNKS.with_borrow(|loaded| loaded.clone());
+    if loaded.is_empty() {
+        return Err(SearchError::NotLoaded);
+    }
+
+    let mut results = Vec::new();
+    let mut error = None;
+    for (name, index) in loaded.iter() {
+        match index.search(query) {
+            Ok(chunk_results) => results.extend(chunk_results),
+            Err(err) => {
+                log::error!("failed to search the {name} index: {err}");
+                error = Some(err);
+            }
+        }
+    }
+
+    match error {
+        Some(err) if results.is_empty() => Err(SearchError::Index(err)),
+        _ => {
+            // Every chunk ranks its own results, so put them back in one order
+            results.sort_by(|a, b| b.score.cmp(&a.score));
+            Ok(results)
+        }
+    }
+}
+
+/// The kind of page a search result links to
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum RouteKind {
+    Docs,
+    Blog,
+    Awesome,
+    Homepage,
+    Other,
+}
+
+impl RouteKind {
+    /// The kinds that can be filtered on in the search modal
+    pub(crate) const FILTERABLE: [RouteKind; 4] = [
+        RouteKind::Docs,
+        RouteKind::Blog,
+        RouteKind::Awesome,
+        RouteKind::Homepage,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            RouteKind::Docs => "Docs",
+            RouteKind::Blog => "Blog",
+            RouteKind::Awesome => "Awesome",
+            RouteKind::Homepage => "Homepage",
+            RouteKind::Other => "Other",
+        }
+    }
+}
+
+/// What a search result can be filtered on.
+///
+/// Every entry of the index stores the route it was rendered from, and the route already knows what
+/// kind of page it is and which version and language of the docs it belongs to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Facets {
+    pub(crate) kind: RouteKind,
+    /// The version of the docs, only set for docs pages
+    pub(crate) version: Option<&'static str>,
+    /// The language code of the docs, only set for docs pages
+    pub(crate) language: Option<&'static str>,
+}
+
+impl Facets {
+    pub(crate) fn of(route: &Route) -> Self {
+        let docs = |version, language| Facets {
+            kind: RouteKind::Docs,
+            version: Some(version),
+            language: Some(language),
+        };
+        match route {
+            Route::Docs { .. } => docs("0.5", DEFAULT_LANGUAGE),
+            Route::DocsTranslated { child } => docs(
+                "0.5",
+                language(child.language()).map_or(DEFAULT_LANGUAGE, |language| language.code),
+            ),
+            Route::DocsO4 { .. } => docs("0.4", DEFAULT_LANGUAGE),
+            Route::DocsO3 { .. } => docs("0.3", DEFAULT_LANGUAGE),
+            Route::Blog { .. } => Facets::other(RouteKind::Blog),
+            Route::Awesome { .. } | Route::AwesomeItem { .. } => Facets::other(RouteKind::Awesome),
+            Route::Homepage {} => Facets::other(RouteKind::Homepage),
+            _ => Facets::other(RouteKind::Other),
+        }
+    }
+
+    fn other(kind: RouteKind) -> Self {
+        Facets {
+            kind,
+            version: None,
+            language: None,
+        }
+    }
+}
+
+/// Every version of the docs with a route, newest first
+const DOCS_VERSIONS: &[&str] = &["0.5", "0.4", "0.3"];
+
+/// The docs versions and languages that have pages in the index, in the order of [`DOCS_VERSIONS`]
+/// and [`LANGUAGES`](crate::docs::router_05::LANGUAGES). Only static routes are indexed, so the 0.3
+/// and 0.4 routes and untranslated pages never show up in the results and get no filter.
+pub(crate) struct IndexedFacets {
+    pub(crate) versions: Vec<&'static str>,
+    pub(crate) languages: Vec<&'static str>,
+}
+
+pub(crate) static INDEXED_FACETS: once_cell::sync::Lazy<IndexedFacets> =
+    once_cell::sync::Lazy::new(|| {
+        let facets: Vec<Facets> = Route::static_routes().iter().map(Facets::of).collect();
+        let indexed = |facet: fn(&Facets) -> Option<&'static str>, value: &str| {
+            facets.iter().any(|facets| facet(facets) == Some(value))
+        };
+        IndexedFacets {
+            versions: DOCS_VERSIONS
+                .iter()
+                .copied()
+                .filter(|version| indexed(|facets| facets.version, version))
+                .collect(),
+            languages: crate::docs::router_05::LANGUAGES
+                .iter()
+                .map(|language| language.code)
+                .filter(|code| indexed(|facets| facets.language, code))
+                .collect(),
+        }
+    });
+
+/// The filters picked in the search modal. `None` lets everything through.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub(crate) struct SearchFilters {
+    pub(crate) kind: Option<RouteKind>,
+    pub(crate) version: Option<&'static str>,
+    pub(crate) language: Option<&'static str>,
+}
+
+impl SearchFilters {
+    pub(crate) fn matches(&self, route: &Route) -> bool {
+        let facets = Facets::of(route);
+        self.kind.map_or(true, |kind| facets.kind == kind)
+            && self.version.map_or(true, |version| facets.version == Some(version))
+            && self.language.map_or(true, |language| facets.language == Some(language))
+    }
+
+    /// Version and language only mean something for the docs
+    pub(crate) fn docs_facets_apply(&self) -> bool {
+        matches!(self.kind, None | Some(RouteKind::Docs))
+    }
+}
@@ -0,0 +1,257 @@
+//! An opt-in log of the searches that found little or nothing, so docs authors learn what readers
+//! can't find.
+//!
+//! Readers opt in from the search modal. The queries are sent to [`record_search_miss`], which appends
+//! them to a JSON lines file on the server. This needs a deployment that runs the `server` feature: the
+//! static site on GitHub Pages has no server, so nothing is recorded there.
+//!
+//! Only prebuild reads the log back, to print a report of the most common misses and to pick the
+//! queries the search modal suggests. The `search-misses` command exports the whole log, and only
+//! rewrites the suggestions committed to the repo when asked to.
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Searches with fewer results than this are worth recording
+pub(crate) const LOW_RESULT_COUNT: usize = 2;
+
+/// How long the query has to stay the same before we record it, so we don't record every prefix of
+/// what is being typed
+pub(crate) const RECORD_DELAY_MS: u32 = 1500;
+
+/// The longest query that is recorded, in characters. Longer queries are cut off.
+pub(crate) const MAX_QUERY_CHARS: usize = 100;
+
+/// The file the suggestions are kept in, in the repo and next to the search index of the site
+const SUGGESTIONS_FILE: &str = "search_suggestions.json";
+
+/// The queries suggested when a search finds nothing. Prebuild writes them next to the search index,
+/// and they are fetched with it. Until then, the suggestions last committed to the repo are shown.
+pub(crate) static SUGGESTIONS: GlobalSignal<Vec<String>> = Signal::global(|| {
+    serde_json::from_str(include_str!("../search_suggestions.json")).unwrap_or_default()
+});
+
+/// Fetch the suggestions prebuild wrote for the deployed index. Keeps the current ones if that fails.
+pub(crate) async fn load_suggestions() {
+    static FETCHED: GlobalSignal<bool> = Signal::global(|| false);
+    if FETCHED() {
+        return;
+    }
+    let fetched = crate::search::fetch_index_file(SUGGESTIONS_FILE).await;
+    if let Some(suggestions) = fetched.ok().and_then(|json| serde_json::from_slice(&json).ok()) {
+        *SUGGESTIONS.write() = suggestions;
+        *FETCHED.write() = true;
+    }
+}
+
+/// Whether the reader opted in to sharing searches that found nothing
+pub(crate) static RECORD_SEARCH_MISSES: GlobalSignal<bool> = Signal::global(|| {
+    #[cfg(feature = "web")]
+    {
+        return local_storage()
+            .and_then(|storage| storage.get_item(OPT_IN_KEY).ok().flatten())
+            .map_or(false, |value| value == "true");
+    }
+    #[allow(unreachable_code)]
+    false
+});
+
+#[cfg(feature = "web")]
+const OPT_IN_KEY: &str = "record-search-misses";
+
+#[cfg(feature = "web")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// Remember the choice of the reader between visits
+pub(crate) fn set_record_search_misses(record: bool) {
+    *RECORD_SEARCH_MISSES.write() = record;
+    #[cfg(feature = "web")]
+    if let Some(storage) = local_storage() {
+        _ = storage.set_item(OPT_IN_KEY, &record.to_string());
+    }
+}
+
+/// A search that found fewer than [`LOW_RESULT_COUNT`] results
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub(crate) struct SearchMiss {
+    pub(crate) query: String,
+    pub(crate) results: usize,
+    pub(crate) time: chrono::DateTime<chrono::Utc>,
+}
+
+/// Record a search that found little. Misses over the rate limit or past the size limit of the log are
+/// dropped without an error, the reader has nothing to do about them.
+#[server]
+pub(crate) async fn record_search_miss(query: String, results: usize) -> Result<(), ServerFnError> {
+    let query = normalize(&query);
+    if query.is_empty() || results >= LOW_RESULT_COUNT {
+        return Ok(());
+    }
+    store::append(&SearchMiss {
+        query,
+        results,
+        time: chrono::Utc::now(),
+    })?;
+    Ok(())
+}
+
+/// Queries that only differ in case or spacing are the same query. Anything past
+/// [`MAX_QUERY_CHARS`] is cut off.
+pub(crate) fn normalize(query: &str) -> String {
+    let query = query.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    query.chars().take(MAX_QUERY_CHARS).collect::<String>().trim_end().to_string()
+}
+
+#[cfg(feature = "server")]
+pub(crate) mod store {
+    use super::*;
+    use std::io::{BufRead, Write};
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    /// The most misses recorded per [`RATE_WINDOW`], from every reader together
+    pub(crate) const RATE_LIMIT: usize = 30;
+    pub(crate) const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+    /// The log stops growing at this size, in bytes, until someone moves it out of the way
+    pub(crate) const MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+    /// Serializes appends so lines from concurrent requests don't interleave, and holds the times of
+    /// the appends in the current rate window
+    static LOCK: Mutex<Vec<Instant>> = Mutex::new(Vec::new());
+
+    /// The file the log is kept in, `search_misses.jsonl` unless `SEARCH_MISSES_PATH` is set
+    pub(crate) fn path() -> PathBuf {
+        std::env::var_os("SEARCH_MISSES_PATH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("search_misses.jsonl"))
+    }
+
+    /// Append a miss to the log, unless the rate limit is reached or the log is full
+    pub(crate) fn append(miss: &SearchMiss) -> std::io::Result<()> {
+        let mut recent = LOCK.lock().unwrap();
+        let now = Instant::now();
+        recent.retain(|time| now.duration_since(*time) < RATE_WINDOW);
+        if recent.len() >= RATE_LIMIT {
+            return Ok(());
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path())?;
+        if file.metadata()?.len() >= MAX_LOG_BYTES {
+            return Ok(());
+        }
+        recent.push(now);
+        writeln!(file, "{}", serde_json::to_string(miss)?)
+    }
+
+    /// Read every recorded miss. A missing log is an empty one, and lines that don't parse are skipped.
+    pub(crate) fn load() -> std::io::Result<Vec<SearchMiss>> {
+        let file = match std::fs::File::open(path()) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut misses = Vec::new();
+        for line in std::io::BufReader::new(file).lines() {
+            if let Ok(miss) = serde_json::from_str(&line?) {
+                misses.push(miss);
+            }
+        }
+        Ok(misses)
+    }
+
+    /// Count how often each query was missed, most common first
+    pub(crate) fn most_common(misses: &[SearchMiss]) -> Vec<(String, usize)> {
+        let mut counts = std::collections::HashMap::<&str, usize>::new();
+        for miss in misses {
+            *counts.entry(&miss.query).or_default() += 1;
+        }
+        let mut counts: Vec<_> = counts
+            .into_iter()
+            .map(|(query, count)| (query.to_string(), count))
+            .collect();
+        counts.sort_by(|(a_query, a_count), (b_query, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_query.cmp(b_query))
+        });
+        counts
+    }
+}
+
+/// How many queries the search modal suggests
+#[cfg(feature = "prebuild")]
+const SUGGESTION_COUNT: usize = 3;
+
+/// Print the most common misses and write the suggested queries next to the search index in
+/// `index_dir`, so the site picks them up without another build. The suggestions committed to the
+/// repo are left alone, [`export`] updates them.
+#[cfg(feature = "prebuild")]
+pub(crate) fn update_suggestions(index_dir: &std::path::Path) -> std::io::Result<()> {
+    let misses = store::most_common(&store::load()?);
+    if !misses.is_empty() {
+        println!("most common searches with few results:");
+    }
+    for (query, count) in misses.iter().take(20) {
+        println!("  {count:>5}  {query}");
+    }
+
+    let json = serde_json::to_string_pretty(&suggestions(index_dir, &misses)?)? + "\n";
+    std::fs::write(index_dir.join(SUGGESTIONS_FILE), json)
+}
+
+/// Print every recorded query with how often it was missed as JSON, most common first. With
+/// `update_repo`, the suggestions committed to the repo are replaced with the ones for the index in
+/// `index_dir`.
+#[cfg(feature = "prebuild")]
+pub(crate) fn export(index_dir: &std::path::Path, update_repo: bool) -> std::io::Result<()> {
+    let misses = store::most_common(&store::load()?);
+    println!("{}", serde_json::to_string_pretty(&misses)?);
+
+    if update_repo {
+        let json = serde_json::to_string_pretty(&suggestions(index_dir, &misses)?)? + "\n";
+        std::fs::write(SUGGESTIONS_FILE, json)?;
+        eprintln!("updated {SUGGESTIONS_FILE}");
+    }
+    Ok(())
+}
+
+/// The queries to suggest for the search index in `index_dir`.
+///
+/// A query that was missed often but finds enough results in that index is exactly what readers were
+/// looking for, so it is suggested first. The suggestions committed to the repo fill up the rest of
+/// the list.
+#[cfg(feature = "prebuild")]
+fn suggestions(
+    index_dir: &std::path::Path,
+    misses: &[(String, usize)],
+) -> std::io::Result<Vec<String>> {
+    crate::search::load_written_chunks(index_dir)?;
+    let current: Vec<String> = std::fs::read_to_string(SUGGESTIONS_FILE)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    let mut suggestions: Vec<String> = misses
+        .iter()
+        .map(|(query, _)| query)
+        .filter(|query| {
+            crate::search::search(query).map_or(false, |results| results.len() >= LOW_RESULT_COUNT)
+        })
+        .take(SUGGESTION_COUNT)
+        .cloned()
+        .collect();
+    for suggestion in &current {
+        if suggestions.len() >= SUGGESTION_COUNT {
+            break;
+        }
+        if !suggestions.iter().any(|query| normalize(query) == normalize(suggestion)) {
+            suggestions.push(suggestion.clone());
+        }
+    }
+    Ok(suggestions)
+}
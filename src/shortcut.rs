@@ -14,8 +14,14 @@ thread_local! {
         let callbacks2 = callbacks.clone();
 
         let cb: Closure<dyn FnMut(web_sys::Event)> = wasm_bindgen::closure::Closure::new(move |evt: web_sys::Event| {
+            let typing = is_typing_target(&evt);
             let data = dioxus::prelude::KeyboardData::from(evt);
             for (_, (key, modifiers, callback)) in callbacks2.lock().unwrap().iter_mut() {
+                // Keys without a modifier belong to whatever is being typed in
+                let chord = Modifiers::CONTROL | Modifiers::ALT | Modifiers::META;
+                if typing && !modifiers.intersects(chord) {
+                    continue;
+                }
                 if data.key() == *key && data.modifiers() == *modifiers {
                     callback();
                 }
@@ -32,6 +38,21 @@ thread_local! {
     };
 }
 
+/// Whether the key was pressed in something that takes text, like the editors of the playground
+/// and the homepage, or in a code block
+#[cfg(feature = "web")]
+fn is_typing_target(evt: &web_sys::Event) -> bool {
+    let Some(element) = evt
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return false;
+    };
+    matches!(element.tag_name().to_ascii_lowercase().as_str(), "input" | "textarea" | "select")
+        || element.is_content_editable()
+        || element.closest("pre").ok().flatten().is_some()
+}
+
 type ShortcutCallbacks = Arc<Mutex<Slab<(Key, Modifiers, Box<dyn FnMut()>)>>>;
 
 struct ShortcutHandler {
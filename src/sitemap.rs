@@ -1,34 +1,107 @@
-pub(crate) static SECTIONS: &[(&str, &[(&str, &str)])] = &[
-    (
-        "Docs",
-        &[
-            ("Installation", "docs/installation"),
-            ("Main Concepts", "docs/main"),
-            ("Advanced Guides", "docs/advanced"),
-            ("Hooks", "docs/hooks"),
-            ("Testing", "docs/testing"),
-            ("Contributing", "docs/contributing"),
-            ("FAQ", "docs/faq"),
-        ],
-    ),
-    (
-        "Channels",
-        &[("Github", "https://github.com/jkelleyrtp/dioxus")],
-    ),
-    (
-        "Community",
-        &[
-            ("Code of Conduct", "docs/installation"),
-            ("Community Resources", "docs/main"),
-        ],
-    ),
-    (
-        "More",
-        &[
-            ("Tutorial", "docs/installation"),
-            ("Blog", "docs/main"),
-            ("Privacy", "docs/advanced"),
-            ("Terms", "docs/hooks"),
-        ],
-    ),
-];
+//! Generates `sitemap.xml` and `robots.txt` for the prebuilt site.
+//!
+//! Every static route of [`Route`] ends up in the sitemap. The `lastmod` of a page is taken from the
+//! last commit that touched its source file (markdown for the docs, the post for the blog), falling
+//! back to the modification time of the file when git isn't available.
+
+use crate::*;
+use dioxus::prelude::*;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// The public URL the site is deployed to, without a trailing slash
+pub(crate) const SITE_URL: &str = "https://dioxuslabs.com";
+
+/// Write `sitemap.xml` and `robots.txt` into the given output directory
+pub(crate) fn generate(out_dir: &Path) -> std::io::Result<()> {
+    std::fs::write(out_dir.join("sitemap.xml"), sitemap_xml())?;
+    std::fs::write(out_dir.join("robots.txt"), robots_txt())?;
+    Ok(())
+}
+
+fn sitemap_xml() -> String {
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push('\n');
+    xml.push_str(r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#);
+    xml.push('\n');
+
+    for route in Route::static_routes() {
+        if !include_in_sitemap(&route) {
+            continue;
+        }
+
+        let loc = format!("{SITE_URL}{}", route.to_string().trim_end_matches('?'));
+        _ = writeln!(xml, "  <url>");
+        _ = writeln!(xml, "    <loc>{}</loc>", escape_xml(&loc));
+        if let Some(lastmod) = source_file(&route).and_then(|path| last_modified(&path)) {
+            _ = writeln!(xml, "    <lastmod>{}</lastmod>", lastmod.format("%Y-%m-%d"));
+        }
+        _ = writeln!(xml, "  </url>");
+    }
+
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+fn robots_txt() -> String {
+    format!("User-agent: *\nAllow: /\n\nSitemap: {SITE_URL}/sitemap.xml\n")
+}
+
+/// Routes that render a real page. Everything else is either a redirect stub or an error page.
+fn include_in_sitemap(route: &Route) -> bool {
+    !matches!(
+        route,
+        Route::Err404 { .. } | Route::DocsO3 { .. } | Route::DocsO4 { .. } | Route::Tutorial { .. }
+    )
+}
+
+/// The file a route is rendered from, if there is one
+fn source_file(route: &Route) -> Option<PathBuf> {
+    match route {
+        Route::Docs { child } => {
+            Some(Path::new(crate::docs::router_05::BOOK_DIR).join(child.source_path()?))
+        }
+        _ => {
+            let url = route.to_string();
+            let post = POSTS
+                .iter()
+                .find(|post| post.link.trim_end_matches('/') == url.trim_end_matches('/'))?;
+            Some(PathBuf::from(post.source))
+        }
+    }
+}
+
+/// Get the date of the last commit that touched a file, or the file's mtime if git doesn't know it
+fn last_modified(path: &Path) -> Option<chrono::DateTime<chrono::Utc>> {
+    let from_git = std::process::Command::new("git")
+        .args(["log", "-1", "--format=%cI", "--"])
+        .arg(path)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            let date = String::from_utf8(output.stdout).ok()?;
+            chrono::DateTime::parse_from_rfc3339(date.trim()).ok()
+        })
+        .map(|date| date.with_timezone(&chrono::Utc));
+
+    from_git.or_else(|| {
+        let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+        Some(modified.into())
+    })
+}
+
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
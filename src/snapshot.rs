@@ -0,0 +1,226 @@
+//! Diffs the prerendered pages against the snapshots committed in `page_snapshots/`.
+//!
+//! Run with `cargo run --features prebuild -- snapshot` after `dx build`. Every route is prerendered
+//! into a temporary directory, normalized so that only changes a reader could notice remain, and
+//! compared with its snapshot. Pass `--update` to replace the snapshots with the current pages once
+//! the changes are expected.
+
+use crate::linkcheck::collect_pages;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+const SNAPSHOT_DIR: &str = "page_snapshots";
+
+/// Prerender the site and compare it with the snapshots. Returns false and prints the diffs if any
+/// page changed, or always writes the snapshots and returns true with `update`.
+pub(crate) async fn run(update: bool) -> bool {
+    let out_dir = std::env::temp_dir().join("dioxus-docs-snapshot");
+    _ = std::fs::remove_dir_all(&out_dir);
+    crate::prerender(out_dir.clone()).await;
+
+    let current = load_pages(&out_dir);
+    let snapshots = if Path::new(SNAPSHOT_DIR).exists() {
+        load_pages(Path::new(SNAPSHOT_DIR))
+    } else {
+        println!("there is no baseline in {SNAPSHOT_DIR}/, every page is reported as new");
+        BTreeMap::new()
+    };
+    _ = std::fs::remove_dir_all(&out_dir);
+
+    if update {
+        _ = std::fs::remove_dir_all(SNAPSHOT_DIR);
+        for (route, html) in &current {
+            let path = snapshot_path(route);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, html).unwrap();
+        }
+        println!("updated the snapshots of {} pages", current.len());
+        return true;
+    }
+
+    let mut changed = 0;
+    for (route, html) in &current {
+        match snapshots.get(route) {
+            Some(snapshot) if snapshot == html => {}
+            Some(snapshot) => {
+                changed += 1;
+                println!(
+                    "\n{route} changed:\n{}",
+                    pretty_assertions::StrComparison::new(snapshot, html)
+                );
+            }
+            None => {
+                changed += 1;
+                println!("\n{route} is new");
+            }
+        }
+    }
+    for route in snapshots.keys().filter(|route| !current.contains_key(*route)) {
+        changed += 1;
+        println!("\n{route} was removed");
+    }
+
+    println!("\ncompared {} pages, {changed} changed", current.len());
+    if changed > 0 {
+        println!("run `cargo run --features prebuild -- snapshot --update` if the changes are expected");
+    }
+    changed == 0
+}
+
+/// The normalized html of every page in a directory, by route
+fn load_pages(dir: &Path) -> BTreeMap<String, String> {
+    let mut pages = Vec::new();
+    collect_pages(dir, dir, &mut pages);
+    pages
+        .into_iter()
+        .map(|(route, path)| {
+            let html = std::fs::read_to_string(&path).unwrap();
+            (route, normalize(&html))
+        })
+        .collect()
+}
+
+fn snapshot_path(route: &str) -> PathBuf {
+    let mut path = PathBuf::from(SNAPSHOT_DIR);
+    for segment in route.split('/').filter(|segment| !segment.is_empty()) {
+        path.push(segment);
+    }
+    path.join("index.html")
+}
+
+/// Remove everything from the html that changes between builds without changing the page: the ids
+/// and data the client needs to hydrate, and the hashes in the names of assets. Every tag starts on
+/// its own line so the diffs point at the element that changed.
+fn normalize(html: &str) -> String {
+    let mut html = remove_attribute(html, "data-node-hydration");
+    html = blank_attribute(&html, "data-serialized");
+    for comment in ["<!--node-id", "<!--placeholder", "<!--#"] {
+        html = remove_comments(&html, comment);
+    }
+    html = remove_hashes(&html);
+
+    let mut normalized = html.replace("><", ">\n<");
+    if !normalized.ends_with('\n') {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// Remove every ` name="..."` from the html
+fn remove_attribute(html: &str, name: &str) -> String {
+    let needle = format!(" {name}=\"");
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(&needle) {
+        output.push_str(&rest[..start]);
+        let value = &rest[start + needle.len()..];
+        rest = match value.find('"') {
+            Some(end) => &value[end + 1..],
+            None => "",
+        };
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Keep the attribute but replace its value, for attributes the page relies on existing
+fn blank_attribute(html: &str, name: &str) -> String {
+    let needle = format!(" {name}=\"");
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(&needle) {
+        output.push_str(&rest[..start + needle.len()]);
+        let value = &rest[start + needle.len()..];
+        rest = match value.find('"') {
+            Some(end) => &value[end..],
+            None => "",
+        };
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Remove the comments that start with `start`
+fn remove_comments(html: &str, start: &str) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(index) = rest.find(start) {
+        output.push_str(&rest[..index]);
+        let comment = &rest[index..];
+        rest = match comment.find("-->") {
+            Some(end) => &comment[end + "-->".len()..],
+            None => "",
+        };
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Replace the hash in the asset urls of `src` and `href` attributes with `[hash]`. Text and other
+/// attributes are left alone, so a change to a hex string in a page still shows up.
+fn remove_hashes(html: &str) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some((start, len)) = [" src=\"", " href=\""]
+        .iter()
+        .filter_map(|needle| Some((rest.find(needle)?, needle.len())))
+        .min()
+    {
+        output.push_str(&rest[..start + len]);
+        let value = &rest[start + len..];
+        let end = value.find('"').unwrap_or(value.len());
+        output.push_str(&remove_hash(&value[..end]));
+        rest = &value[end..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Replace the hash in an asset url like `/assets/main-4f2a9c81e0.css` or `app.4f2a9c81e0.js`
+fn remove_hash(url: &str) -> String {
+    let mut output = String::with_capacity(url.len());
+    let mut chars = url.char_indices();
+    while let Some((index, c)) = chars.next() {
+        output.push(c);
+        if !matches!(c, '-' | '.' | '_') {
+            continue;
+        }
+        let rest = &url[index + 1..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_hexdigit())
+            .unwrap_or(rest.len());
+        let hash = &rest[..len];
+        let is_hash = len >= 8
+            && hash.chars().any(|c| c.is_ascii_digit())
+            && rest[len..].starts_with('.');
+        if is_hash {
+            output.push_str("[hash]");
+            for _ in 0..len {
+                chars.next();
+            }
+        }
+    }
+    output
+}
+
+#[test]
+fn normalizes_hydration_and_hashes() {
+    let html = concat!(
+        r#"<div data-node-hydration="0,click:1"><!--node-id2-->Hello<!--#--></div>"#,
+        r#"<link href="/assets/main-4f2a9c81e0.css"><meta id="data" data-serialized="AAEC"/>"#,
+        r#"<a href="/docs/0.5/guide/index.html">guide</a>"#,
+        r#"<script src="./app.4f2a9c81e0.js"></script><p>commit-4f2a9c81e0.txt</p>"#,
+    );
+    assert_eq!(
+        normalize(html),
+        concat!(
+            "<div>Hello</div>\n",
+            "<link href=\"/assets/main-[hash].css\">\n",
+            "<meta id=\"data\" data-serialized=\"\"/>\n",
+            "<a href=\"/docs/0.5/guide/index.html\">guide</a>\n",
+            "<script src=\"./app.[hash].js\">\n",
+            "</script>\n",
+            "<p>commit-4f2a9c81e0.txt</p>\n",
+        )
+    );
+}